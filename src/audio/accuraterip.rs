@@ -0,0 +1,223 @@
+use super::TrackChecksums;
+use crate::{
+    device::Toc,
+    error::{Error, ErrorKind, Result},
+};
+use std::{fmt, fs, path::Path};
+
+// Track count and the three disc IDs
+const CHUNK_HEADER_LEN: usize = 13;
+// Confidence, checksum and checksum of the frame used to detect offsets
+const CHUNK_TRACK_LEN: usize = 9;
+
+/// Identifier of a disc in the AccurateRip database, calculated from its TOC.
+///
+/// # Example
+///
+/// ```no_run
+/// use eject::{audio::AccurateRipId, device::Device};
+///
+/// let toc = Device::open("/dev/cdrom")?.toc()?;
+/// let id = AccurateRipId::from_toc(&toc);
+/// println!("Database file: {}", id.url());
+/// # eject::error::Result::Ok(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct AccurateRipId {
+    /// Number of tracks in the disc.
+    pub track_count: u8,
+    /// Sum of the addresses where the audio tracks start, plus the end of the last track.
+    pub id1: u32,
+    /// Sum of those addresses multiplied by their track number.
+    pub id2: u32,
+    /// The freedb disc ID.
+    pub freedb_id: u32,
+}
+
+impl AccurateRipId {
+    /// Calculates the AccurateRip ID of the disc with this TOC.
+    ///
+    /// Data tracks aren't included in the ID, but they are counted.
+    pub fn from_toc(toc: &Toc) -> Self {
+        let mut id1: u32 = 0;
+        let mut id2: u32 = 0;
+        for track in toc.tracks.iter().filter(|track| track.control.is_audio()) {
+            id1 = id1.wrapping_add(track.start_lba);
+            id2 = id2.wrapping_add(track.start_lba.max(1).wrapping_mul(track.number.into()));
+        }
+        let track_count = toc.tracks.len() as u8;
+        id1 = id1.wrapping_add(toc.lead_out_lba);
+        id2 = id2.wrapping_add(toc.lead_out_lba.max(1).wrapping_mul(track_count as u32 + 1));
+        Self {
+            track_count,
+            id1,
+            id2,
+            freedb_id: toc.freedb_id(),
+        }
+    }
+
+    /// Returns the name of the database file with the checksums of this disc.
+    pub fn file_name(&self) -> String {
+        format!(
+            "dBAR-{:03}-{:08x}-{:08x}-{:08x}.bin",
+            self.track_count, self.id1, self.id2, self.freedb_id
+        )
+    }
+
+    /// Returns the URL where the database file of this disc can be downloaded.
+    pub fn url(&self) -> String {
+        format!(
+            "http://www.accuraterip.com/accuraterip/{:x}/{:x}/{:x}/{}",
+            self.id1 & 0xf,
+            self.id1 >> 4 & 0xf,
+            self.id1 >> 8 & 0xf,
+            self.file_name()
+        )
+    }
+}
+
+impl fmt::Display for AccurateRipId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:03}-{:08x}-{:08x}-{:08x}",
+            self.track_count, self.id1, self.id2, self.freedb_id
+        )
+    }
+}
+
+/// Checksums of the pressings of a disc, read from an AccurateRip database file.
+///
+/// # Example
+///
+/// ```no_run
+/// use eject::{
+///     audio::{AccurateRipDatabase, AccurateRipId, Ripper},
+///     device::Device,
+/// };
+/// use std::io;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let cdrom = Device::open("/dev/cdrom")?;
+/// let mut ripper = Ripper::new(&cdrom)?;
+/// let id = AccurateRipId::from_toc(ripper.toc());
+/// let database = AccurateRipDatabase::open(id.file_name())?;
+/// let checksums = ripper.rip_track(1, io::sink())?;
+/// match database.verify(&id, 1, &checksums) {
+///     Some(found) => println!("Accurate, confidence {}", found.confidence),
+///     None => println!("Not found in the database"),
+/// }
+/// # Ok(())}
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AccurateRipDatabase {
+    pressings: Vec<Pressing>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Pressing {
+    id: AccurateRipId,
+    // Confidence and checksum of each track
+    tracks: Vec<(u8, u32)>,
+}
+
+impl AccurateRipDatabase {
+    /// Reads a database file, as downloaded from [`AccurateRipId::url`].
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::parse(&fs::read(path)?)
+    }
+
+    /// Parses the contents of a database file.
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        let mut pressings = Vec::new();
+        let mut rest = data;
+        while !rest.is_empty() {
+            let invalid = || {
+                Error::new(
+                    ErrorKind::Unknown,
+                    "the AccurateRip database file is truncated",
+                )
+            };
+            let header = rest.get(..CHUNK_HEADER_LEN).ok_or_else(invalid)?;
+            let u32_at = |bytes: &[u8], i: usize| {
+                u32::from_le_bytes([bytes[i], bytes[i + 1], bytes[i + 2], bytes[i + 3]])
+            };
+            let track_count = header[0];
+            let tracks_len = track_count as usize * CHUNK_TRACK_LEN;
+            let tracks = rest
+                .get(CHUNK_HEADER_LEN..CHUNK_HEADER_LEN + tracks_len)
+                .ok_or_else(invalid)?;
+            pressings.push(Pressing {
+                id: AccurateRipId {
+                    track_count,
+                    id1: u32_at(header, 1),
+                    id2: u32_at(header, 5),
+                    freedb_id: u32_at(header, 9),
+                },
+                tracks: tracks
+                    .chunks_exact(CHUNK_TRACK_LEN)
+                    .map(|track| (track[0], u32_at(track, 1)))
+                    .collect(),
+            });
+            rest = &rest[CHUNK_HEADER_LEN + tracks_len..];
+        }
+        Ok(Self { pressings })
+    }
+
+    /// Looks for the checksums of a track in the database, returning the match with
+    /// the highest confidence.
+    ///
+    /// `track` is the position of the track in the disc, starting at 1,
+    /// which is usually the same as its number.
+    pub fn verify(
+        &self,
+        id: &AccurateRipId,
+        track: u8,
+        checksums: &TrackChecksums,
+    ) -> Option<AccurateRipMatch> {
+        let index = usize::from(track).checked_sub(1)?;
+        self.pressings
+            .iter()
+            .filter(|pressing| pressing.id == *id)
+            .filter_map(|pressing| {
+                let &(confidence, checksum) = pressing.tracks.get(index)?;
+                let version = if checksum == checksums.accuraterip_v2 {
+                    ChecksumVersion::V2
+                } else if checksum == checksums.accuraterip_v1 {
+                    ChecksumVersion::V1
+                } else {
+                    return None;
+                };
+                Some(AccurateRipMatch {
+                    version,
+                    confidence,
+                })
+            })
+            .max_by_key(|found| found.confidence)
+    }
+
+    /// Returns whether the database has any checksums for this disc.
+    pub fn contains(&self, id: &AccurateRipId) -> bool {
+        self.pressings.iter().any(|pressing| pressing.id == *id)
+    }
+}
+
+/// A track checksum found in an [`AccurateRipDatabase`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct AccurateRipMatch {
+    /// Version of the checksum that matched.
+    pub version: ChecksumVersion,
+    /// Number of submitted rips with this checksum.
+    pub confidence: u8,
+}
+
+/// Version of an AccurateRip checksum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ChecksumVersion {
+    /// The original checksum, which ignores some bits of the samples.
+    V1,
+    /// The checksum that includes all the bits of every sample.
+    V2,
+}
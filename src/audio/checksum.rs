@@ -0,0 +1,175 @@
+use super::SAMPLES_PER_SECTOR;
+
+// Samples at the start of the first track and the end of the last one that
+// AccurateRip ignores, because drives with different offsets can't read them
+const SKIPPED_SAMPLES: u64 = 5 * SAMPLES_PER_SECTOR as u64;
+
+/// Checksums of the audio of a track, returned when ripping it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub struct TrackChecksums {
+    /// CRC32 of all the samples, as shown by other rippers as the copy CRC.
+    pub crc32: u32,
+    /// AccurateRip v1 checksum.
+    pub accuraterip_v1: u32,
+    /// AccurateRip v2 checksum.
+    pub accuraterip_v2: u32,
+}
+
+/// Calculates the standard CRC32 of some data, as used by zip and PNG.
+///
+/// # Example
+///
+/// ```
+/// use eject::audio::Crc32;
+///
+/// let mut crc = Crc32::new();
+/// crc.update(b"123456789");
+/// assert_eq!(crc.value(), 0xcbf43926);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Crc32 {
+    state: u32,
+}
+
+impl Crc32 {
+    /// Creates a CRC32 of no data.
+    pub fn new() -> Self {
+        Self { state: !0 }
+    }
+
+    /// Adds data to the checksum.
+    pub fn update(&mut self, data: &[u8]) {
+        for &byte in data {
+            self.state =
+                CRC32_TABLE[((self.state ^ byte as u32) & 0xff) as usize] ^ (self.state >> 8);
+        }
+    }
+
+    /// Returns the checksum of all the data added so far.
+    pub fn value(&self) -> u32 {
+        !self.state
+    }
+}
+
+impl Default for Crc32 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut value = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            value = if value & 1 != 0 {
+                0xedb88320 ^ (value >> 1)
+            } else {
+                value >> 1
+            };
+            bit += 1;
+        }
+        table[i] = value;
+        i += 1;
+    }
+    table
+};
+
+/// Calculates the AccurateRip v1 and v2 checksums of a track.
+///
+/// The first 5 sectors of the first track and the last 5 sectors of the last one
+/// aren't included, so the checksums match those of drives with other read offsets.
+///
+/// # Example
+///
+/// ```
+/// use eject::audio::AccurateRipChecksum;
+///
+/// let mut checksum = AccurateRipChecksum::new(2, false, false);
+/// checksum.update(&[1, 0, 0, 0, 1, 0, 0, 0]);
+/// assert_eq!(checksum.v1(), 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AccurateRipChecksum {
+    // Position of the next sample in the track, starting at 1
+    position: u64,
+    first_counted: u64,
+    last_counted: u64,
+    low: u32,
+    high: u32,
+}
+
+impl AccurateRipChecksum {
+    /// Creates the checksum of a track with this number of stereo samples,
+    /// which may be the first or last track of the disc.
+    pub fn new(samples: u64, first_track: bool, last_track: bool) -> Self {
+        Self {
+            position: 1,
+            first_counted: if first_track { SKIPPED_SAMPLES } else { 1 },
+            last_counted: if last_track {
+                samples.saturating_sub(SKIPPED_SAMPLES)
+            } else {
+                samples
+            },
+            low: 0,
+            high: 0,
+        }
+    }
+
+    /// Adds the next samples of the track, as 16-bit little endian stereo samples.
+    ///
+    /// A trailing incomplete sample is ignored.
+    pub fn update(&mut self, data: &[u8]) {
+        for sample in data.chunks_exact(4) {
+            let sample = u32::from_le_bytes([sample[0], sample[1], sample[2], sample[3]]);
+            if (self.first_counted..=self.last_counted).contains(&self.position) {
+                let product = sample as u64 * (self.position & 0xffff_ffff);
+                self.low = self.low.wrapping_add(product as u32);
+                self.high = self.high.wrapping_add((product >> 32) as u32);
+            }
+            self.position += 1;
+        }
+    }
+
+    /// Returns the AccurateRip v1 checksum of the samples added so far.
+    pub fn v1(&self) -> u32 {
+        self.low
+    }
+
+    /// Returns the AccurateRip v2 checksum of the samples added so far.
+    pub fn v2(&self) -> u32 {
+        self.low.wrapping_add(self.high)
+    }
+}
+
+/// Calculates all the [`TrackChecksums`] of a track at once.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TrackChecksummer {
+    crc32: Crc32,
+    accuraterip: AccurateRipChecksum,
+}
+
+impl TrackChecksummer {
+    pub fn new(samples: u64, first_track: bool, last_track: bool) -> Self {
+        Self {
+            crc32: Crc32::new(),
+            accuraterip: AccurateRipChecksum::new(samples, first_track, last_track),
+        }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        self.crc32.update(data);
+        self.accuraterip.update(data);
+    }
+
+    pub fn checksums(&self) -> TrackChecksums {
+        TrackChecksums {
+            crc32: self.crc32.value(),
+            accuraterip_v1: self.accuraterip.v1(),
+            accuraterip_v2: self.accuraterip.v2(),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! Extract the audio tracks of a CD.
+//!
+//! Audio is read with the READ CD command as raw CD-DA sectors, which contain
+//! 16-bit little endian stereo samples at 44.1 kHz. A [`Ripper`] reads them
+//! track by track and writes them either as a WAV file or as raw PCM.
+//! Tracks can also be ripped securely, reading every sector more than once
+//! to detect errors, and verified with their AccurateRip checksums.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use eject::{audio::Ripper, device::Device};
+//! use std::{fs::File, io::BufWriter};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let cdrom = Device::open("/dev/cdrom")?;
+//! let mut ripper = Ripper::new(&cdrom)?;
+//! ripper.on_progress(|progress| {
+//!     println!("Track {}: {:.0}%", progress.track, progress.fraction() * 100.0);
+//!     std::ops::ControlFlow::Continue(())
+//! });
+//! let numbers: Vec<u8> = ripper.audio_tracks().map(|track| track.number).collect();
+//! for number in numbers {
+//!     let file = File::create(format!("track{number:02}.wav"))?;
+//!     ripper.rip_track_to_wav(number, BufWriter::new(file))?;
+//! }
+//! # Ok(())}
+//! ```
+
+mod accuraterip;
+mod checksum;
+mod ripper;
+pub(crate) mod secure;
+mod wav;
+
+pub use self::{
+    accuraterip::{AccurateRipDatabase, AccurateRipId, AccurateRipMatch, ChecksumVersion},
+    checksum::{AccurateRipChecksum, Crc32, TrackChecksums},
+    ripper::{RipProgress, Ripper},
+    secure::{SecureOptions, SecureRipReport},
+    wav::write_wav_header,
+};
+
+/// Number of bytes in a CD-DA sector.
+pub const SECTOR_LEN: usize = 2352;
+/// Number of stereo samples in a CD-DA sector.
+pub const SAMPLES_PER_SECTOR: usize = SECTOR_LEN / 4;
+/// Number of samples per second in each channel.
+pub const SAMPLE_RATE: u32 = 44100;
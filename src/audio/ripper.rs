@@ -0,0 +1,315 @@
+use super::{
+    checksum::TrackChecksummer,
+    secure::{self, SecureOptions, SecureRipReport},
+    write_wav_header, TrackChecksums, SECTOR_LEN,
+};
+use crate::{
+    device::{Device, Toc, TocTrack},
+    error::{Error, ErrorKind, Result},
+};
+use std::{
+    io::Write,
+    ops::{ControlFlow, Range},
+};
+
+// Sectors read at once, staying below the 64 KiB transfer limit of many drivers
+const SECTORS_PER_READ: u32 = 24;
+
+type ProgressCallback<'a> = Box<dyn FnMut(&RipProgress) -> ControlFlow<()> + 'a>;
+
+/// Reads the audio tracks of a CD, created with [`Ripper::new`].
+///
+/// See the [module docs][super] for an example.
+pub struct Ripper<'a> {
+    device: &'a Device,
+    toc: Toc,
+    read_offset: i32,
+    on_progress: Option<ProgressCallback<'a>>,
+}
+
+impl<'a> Ripper<'a> {
+    /// Creates a ripper for the disc inserted in this device, reading its TOC.
+    pub fn new(device: &'a Device) -> Result<Self> {
+        Ok(Self::with_toc(device, device.toc()?))
+    }
+
+    /// Creates a ripper that uses an already known TOC.
+    pub fn with_toc(device: &'a Device, toc: Toc) -> Self {
+        Self {
+            device,
+            toc,
+            read_offset: 0,
+            on_progress: None,
+        }
+    }
+
+    /// Returns the table of contents of the disc.
+    pub fn toc(&self) -> &Toc {
+        &self.toc
+    }
+
+    /// Returns the tracks of the disc that contain audio.
+    pub fn audio_tracks(&self) -> impl Iterator<Item = &TocTrack> {
+        self.toc
+            .tracks
+            .iter()
+            .filter(|track| track.control.is_audio())
+    }
+
+    /// Sets a function that will be called each time some sectors are read.
+    ///
+    /// If it returns [`ControlFlow::Break`], ripping will stop and return an
+    /// error of kind [`ErrorKind::Cancelled`].
+    pub fn on_progress(
+        &mut self,
+        callback: impl FnMut(&RipProgress) -> ControlFlow<()> + 'a,
+    ) -> &mut Self {
+        self.on_progress = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets the read offset of the drive, in stereo samples, which will be corrected
+    /// when ripping.
+    ///
+    /// This is the number of samples that the drive returns later than it should, as
+    /// listed in the AccurateRip drive offset database. With a positive offset, each
+    /// track is read that many samples after its start. Samples that can't be read
+    /// because they're outside the disc are replaced with silence.
+    pub fn set_read_offset(&mut self, samples: i32) -> &mut Self {
+        self.read_offset = samples;
+        self
+    }
+
+    /// Returns the read offset set with [`set_read_offset`][Self::set_read_offset].
+    pub fn read_offset(&self) -> i32 {
+        self.read_offset
+    }
+
+    /// Returns the size in bytes of the audio of this track.
+    pub fn track_len(&self, number: u8) -> Result<u64> {
+        let sectors = self.track_sectors(number)?;
+        Ok((sectors.end - sectors.start) as u64 * SECTOR_LEN as u64)
+    }
+
+    /// Reads a track and passes its audio to `sink`, as 16-bit little endian
+    /// stereo samples.
+    pub fn rip_track_with(
+        &mut self,
+        number: u8,
+        sink: impl FnMut(&[u8]) -> std::io::Result<()>,
+    ) -> Result<TrackChecksums> {
+        let read_chunk = |device: &Device, lba, count, _| {
+            let mut data = vec![0; count as usize * SECTOR_LEN];
+            device.read_cdda(lba, &mut data)?;
+            Ok(data)
+        };
+        self.rip(number, read_chunk, sink)
+    }
+
+    /// Reads a track and writes its audio as raw PCM, with 16-bit little endian
+    /// stereo samples.
+    pub fn rip_track(&mut self, number: u8, mut writer: impl Write) -> Result<TrackChecksums> {
+        let checksums = self.rip_track_with(number, |data| writer.write_all(data))?;
+        writer.flush()?;
+        Ok(checksums)
+    }
+
+    /// Reads a track and writes it as a WAV file.
+    pub fn rip_track_to_wav(
+        &mut self,
+        number: u8,
+        mut writer: impl Write,
+    ) -> Result<TrackChecksums> {
+        self.write_wav_header(number, &mut writer)?;
+        self.rip_track(number, writer)
+    }
+
+    /// Reads a track verifying every sector and passes its audio to `sink`, as 16-bit
+    /// little endian stereo samples.
+    ///
+    /// Each sector is read until it has been read [`SecureOptions::min_matches`] times
+    /// with the same contents and without C2 errors, or until it has been read
+    /// [`SecureOptions::max_reads`] times. In that case the contents read most often
+    /// are used, and the samples that differed between reads or had C2 errors
+    /// are listed in the returned report. Sectors that can't be read at all
+    /// are replaced with silence.
+    ///
+    /// Drives with a read cache may return the same data for every read of a sector.
+    /// To avoid this, a distant sector is read before reading sectors again, see
+    /// [`SecureOptions::defeat_cache`].
+    pub fn rip_track_secure_with(
+        &mut self,
+        number: u8,
+        options: &SecureOptions,
+        sink: impl FnMut(&[u8]) -> std::io::Result<()>,
+    ) -> Result<SecureRipReport> {
+        let track_samples = self.track_len(number)? / 4;
+        let mut report = SecureRipReport::new(number, options.c2_errors);
+        let read_chunk = |device: &Device, lba, count, first_sample| {
+            secure::read_chunk(
+                device,
+                lba,
+                count,
+                first_sample,
+                track_samples,
+                options,
+                &mut report,
+            )
+        };
+        let checksums = self.rip(number, read_chunk, sink)?;
+        report.checksums = checksums;
+        Ok(report)
+    }
+
+    /// Reads a track verifying every sector and writes its audio as raw PCM,
+    /// with 16-bit little endian stereo samples.
+    ///
+    /// See [`rip_track_secure_with`][Self::rip_track_secure_with] for details.
+    pub fn rip_track_secure(
+        &mut self,
+        number: u8,
+        options: &SecureOptions,
+        mut writer: impl Write,
+    ) -> Result<SecureRipReport> {
+        let report = self.rip_track_secure_with(number, options, |data| writer.write_all(data))?;
+        writer.flush()?;
+        Ok(report)
+    }
+
+    /// Reads a track verifying every sector and writes it as a WAV file.
+    ///
+    /// See [`rip_track_secure_with`][Self::rip_track_secure_with] for details.
+    pub fn rip_track_secure_to_wav(
+        &mut self,
+        number: u8,
+        options: &SecureOptions,
+        mut writer: impl Write,
+    ) -> Result<SecureRipReport> {
+        self.write_wav_header(number, &mut writer)?;
+        self.rip_track_secure(number, options, writer)
+    }
+
+    /// Reads a track in chunks with `read_chunk`, correcting the read offset,
+    /// and passes its audio to `sink`.
+    ///
+    /// `read_chunk` gets the address and number of sectors to read, and the position
+    /// in the track of the first sample in them.
+    fn rip(
+        &mut self,
+        number: u8,
+        mut read_chunk: impl FnMut(&Device, u32, u32, i64) -> Result<Vec<u8>>,
+        mut sink: impl FnMut(&[u8]) -> std::io::Result<()>,
+    ) -> Result<TrackChecksums> {
+        let sector_len = SECTOR_LEN as i64;
+        let sectors = self.track_sectors(number)?;
+        let track_len = (sectors.end - sectors.start) as i64 * sector_len;
+        // Position of the audio of the track on the disc, in bytes
+        let begin = sectors.start as i64 * sector_len + self.read_offset as i64 * 4;
+        let end = begin + track_len;
+        let first_lba = begin.div_euclid(sector_len);
+        let end_lba = (end + sector_len - 1).div_euclid(sector_len);
+        let readable = 0..self.toc.lead_out_lba as i64;
+        let mut checksummer = TrackChecksummer::new(
+            track_len as u64 / 4,
+            self.audio_tracks().next().map(|track| track.number) == Some(number),
+            self.audio_tracks().last().map(|track| track.number) == Some(number),
+        );
+        let mut progress = RipProgress {
+            track: number,
+            sectors_read: 0,
+            track_sectors: sectors.end - sectors.start,
+        };
+        for chunk_lba in (first_lba..end_lba).step_by(SECTORS_PER_READ as usize) {
+            let chunk_end_lba = end_lba.min(chunk_lba + SECTORS_PER_READ as i64);
+            // Sectors before the start or after the end of the disc can't be read,
+            // so they're replaced with silence
+            let read_lba = chunk_lba.clamp(readable.start, readable.end);
+            let read_end_lba = chunk_end_lba.clamp(readable.start, readable.end);
+            let mut data = vec![0; ((read_lba - chunk_lba) * sector_len) as usize];
+            if read_lba < read_end_lba {
+                let first_sample = (read_lba * sector_len - begin) / 4;
+                let count = (read_end_lba - read_lba) as u32;
+                data.extend(read_chunk(
+                    self.device,
+                    read_lba as u32,
+                    count,
+                    first_sample,
+                )?);
+            }
+            data.resize(((chunk_end_lba - chunk_lba) * sector_len) as usize, 0);
+            let chunk_begin = chunk_lba * sector_len;
+            let chunk_end = chunk_end_lba * sector_len;
+            let audio = &data[(begin.max(chunk_begin) - chunk_begin) as usize
+                ..(end.min(chunk_end) - chunk_begin) as usize];
+            checksummer.update(audio);
+            sink(audio)?;
+            progress.sectors_read = ((end.min(chunk_end) - begin) / sector_len) as u32;
+            self.report_progress(&progress)?;
+        }
+        Ok(checksummer.checksums())
+    }
+
+    fn write_wav_header(&self, number: u8, writer: impl Write) -> Result<()> {
+        let data_len = u32::try_from(self.track_len(number)?).map_err(|_| {
+            Error::new(
+                ErrorKind::UnsupportedOperation,
+                format!("track {number} is too long for a WAV file"),
+            )
+        })?;
+        write_wav_header(writer, data_len)?;
+        Ok(())
+    }
+
+    /// Calls the progress callback, returning an error if it cancels the rip.
+    fn report_progress(&mut self, progress: &RipProgress) -> Result<()> {
+        if let Some(callback) = &mut self.on_progress {
+            if callback(progress).is_break() {
+                return Err(Error::new(
+                    ErrorKind::Cancelled,
+                    format!("ripping of track {} was cancelled", progress.track),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the addresses of the sectors of an audio track.
+    fn track_sectors(&self, number: u8) -> Result<Range<u32>> {
+        let track = self.toc.track(number).ok_or_else(|| {
+            Error::new(
+                ErrorKind::NotFound,
+                format!("the disc doesn't have a track {number}"),
+            )
+        })?;
+        if track.control.is_data() {
+            return Err(Error::new(
+                ErrorKind::UnsupportedOperation,
+                format!("track {number} is a data track"),
+            ));
+        }
+        let end = self.toc.track_end(number).unwrap_or(track.start_lba);
+        Ok(track.start_lba..end.max(track.start_lba))
+    }
+}
+
+/// Progress of a rip, passed to the callback set with [`Ripper::on_progress`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct RipProgress {
+    /// Number of the track being read.
+    pub track: u8,
+    /// Number of sectors of the track read so far.
+    pub sectors_read: u32,
+    /// Total number of sectors in the track.
+    pub track_sectors: u32,
+}
+
+impl RipProgress {
+    /// Returns the fraction of the track read so far, from 0 to 1.
+    pub fn fraction(&self) -> f64 {
+        if self.track_sectors == 0 {
+            return 1.0;
+        }
+        self.sectors_read as f64 / self.track_sectors as f64
+    }
+}
@@ -0,0 +1,306 @@
+use super::{TrackChecksums, SAMPLES_PER_SECTOR, SECTOR_LEN};
+use crate::{
+    device::{C2ErrorInfo, CdSector, Device, ReadCdOptions},
+    error::{ErrorKind, Result},
+};
+use std::ops::Range;
+
+// Distance of the sector read to defeat the cache from the sectors being verified,
+// much more than the cache of common drives can hold
+const CACHE_DISTANCE: u32 = 10_000;
+
+/// Options for secure ripping with [`Ripper::rip_track_secure`][super::Ripper::rip_track_secure].
+///
+/// # Example
+///
+/// ```
+/// use eject::audio::SecureOptions;
+///
+/// let options = SecureOptions {
+///     max_reads: 32,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SecureOptions {
+    /// Number of identical reads without C2 errors needed to accept a sector.
+    pub min_matches: u32,
+    /// Maximum number of times a sector will be read before giving up on it.
+    pub max_reads: u32,
+    /// Ask the drive for C2 error pointers. This is disabled automatically
+    /// if the drive doesn't support them.
+    pub c2_errors: bool,
+    /// Read a sector far from the ones being verified before reading them again,
+    /// so the drive reads them from the disc instead of returning its cached data.
+    /// Disabling this is faster with drives that don't cache audio.
+    pub defeat_cache: bool,
+}
+
+impl Default for SecureOptions {
+    fn default() -> Self {
+        Self {
+            min_matches: 2,
+            max_reads: 16,
+            c2_errors: true,
+            defeat_cache: true,
+        }
+    }
+}
+
+/// Results of ripping a track with [`Ripper::rip_track_secure`][super::Ripper::rip_track_secure].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct SecureRipReport {
+    /// Number of the track.
+    pub track: u8,
+    /// Number of sectors read to extract the track, which may include one sector
+    /// of the next or previous track due to the read offset.
+    pub sectors: u32,
+    /// Number of sectors that were read identically enough times without C2 errors.
+    pub verified_sectors: u32,
+    /// Number of sectors read again because the first reads didn't match.
+    pub rereads: u32,
+    /// Whether C2 error pointers were used to detect errors.
+    pub c2_used: bool,
+    /// Ranges of stereo samples, counted from the start of the track,
+    /// whose value couldn't be verified.
+    pub suspicious_samples: Vec<Range<u64>>,
+    /// Checksums of the extracted audio.
+    pub checksums: TrackChecksums,
+}
+
+impl SecureRipReport {
+    pub(crate) fn new(track: u8, c2_used: bool) -> Self {
+        Self {
+            track,
+            sectors: 0,
+            verified_sectors: 0,
+            rereads: 0,
+            c2_used,
+            suspicious_samples: Vec::new(),
+            checksums: TrackChecksums::default(),
+        }
+    }
+
+    /// Returns the fraction of sectors that were verified, from 0 to 1.
+    pub fn confidence(&self) -> f64 {
+        if self.sectors == 0 {
+            return 1.0;
+        }
+        self.verified_sectors as f64 / self.sectors as f64
+    }
+
+    /// Returns whether all sectors of the track were verified.
+    pub fn is_verified(&self) -> bool {
+        self.verified_sectors == self.sectors
+    }
+
+    /// Adds a suspicious sample, merging it with the last range if they're contiguous.
+    fn add_suspicious_sample(&mut self, sample: u64) {
+        match self.suspicious_samples.last_mut() {
+            Some(range) if range.end == sample => range.end += 1,
+            _ => self.suspicious_samples.push(sample..sample + 1),
+        }
+    }
+}
+
+/// A distinct content read for a sector.
+#[derive(Debug)]
+struct Version {
+    data: Vec<u8>,
+    // Reads of this content without C2 errors
+    clean_reads: u32,
+    // Reads of this content with C2 errors
+    c2_reads: u32,
+    // C2 error pointers of all reads of this content, combined
+    c2_errors: Vec<u8>,
+}
+
+/// Every content read for a sector and how many times it was read.
+#[derive(Debug, Default)]
+pub(crate) struct SectorReads {
+    versions: Vec<Version>,
+    reads: u32,
+}
+
+impl SectorReads {
+    pub fn add(&mut self, data: &[u8], c2_errors: &[u8]) {
+        self.reads += 1;
+        let has_c2_errors = c2_errors.iter().any(|&byte| byte != 0);
+        let version = match self
+            .versions
+            .iter()
+            .position(|version| version.data == data)
+        {
+            Some(index) => &mut self.versions[index],
+            None => {
+                self.versions.push(Version {
+                    data: data.to_vec(),
+                    clean_reads: 0,
+                    c2_reads: 0,
+                    c2_errors: Vec::new(),
+                });
+                self.versions.last_mut().unwrap()
+            }
+        };
+        if has_c2_errors {
+            version.c2_reads += 1;
+            version
+                .c2_errors
+                .resize(c2_errors.len().max(version.c2_errors.len()), 0);
+            for (combined, byte) in version.c2_errors.iter_mut().zip(c2_errors) {
+                *combined |= byte;
+            }
+        } else {
+            version.clean_reads += 1;
+        }
+    }
+
+    /// Records a read that failed.
+    pub fn add_failure(&mut self) {
+        self.reads += 1;
+    }
+
+    pub fn reads(&self) -> u32 {
+        self.reads
+    }
+
+    /// Returns whether some content was read at least `min_matches` times without C2 errors.
+    pub fn is_verified(&self, min_matches: u32) -> bool {
+        self.best()
+            .is_some_and(|version| version.clean_reads >= min_matches)
+    }
+
+    /// Returns the most likely content of the sector, or silence if it couldn't be read.
+    pub fn data(&self) -> &[u8] {
+        const SILENCE: [u8; SECTOR_LEN] = [0; SECTOR_LEN];
+        self.best().map_or(&SILENCE[..], |version| &version.data)
+    }
+
+    /// Returns whether each byte of [`data`][Self::data] may be wrong, because it
+    /// had C2 errors or was read with other values.
+    pub fn suspicious_bytes(&self) -> Vec<bool> {
+        let Some(best) = self.best() else {
+            return vec![true; SECTOR_LEN];
+        };
+        (0..best.data.len())
+            .map(|i| {
+                let c2_error = best
+                    .c2_errors
+                    .get(i / 8)
+                    .is_some_and(|byte| byte & (0x80 >> (i % 8)) != 0);
+                c2_error
+                    || self
+                        .versions
+                        .iter()
+                        .any(|version| version.data.get(i) != best.data.get(i))
+            })
+            .collect()
+    }
+
+    fn best(&self) -> Option<&Version> {
+        self.versions
+            .iter()
+            .max_by_key(|version| (version.clean_reads, version.c2_reads))
+    }
+}
+
+/// Reads sectors until each one is verified or reaches the read limit, and returns
+/// their most likely contents.
+///
+/// `first_sample` is the position in the track of the first sample read, which is
+/// negative if the read offset moves it before the start. Only samples of the track,
+/// from 0 to `track_samples`, are added to the suspicious samples.
+pub(crate) fn read_chunk(
+    device: &Device,
+    lba: u32,
+    count: u32,
+    first_sample: i64,
+    track_samples: u64,
+    options: &SecureOptions,
+    report: &mut SecureRipReport,
+) -> Result<Vec<u8>> {
+    let mut reads: Vec<SectorReads> = (0..count).map(|_| SectorReads::default()).collect();
+    for pass in 0..options.min_matches {
+        if pass > 0 && options.defeat_cache {
+            defeat_cache(device, lba);
+        }
+        match read_sectors(device, lba, count, report) {
+            Ok(sectors) => {
+                for (sector_reads, sector) in reads.iter_mut().zip(sectors) {
+                    sector_reads.add(&sector.data, &sector.c2_errors);
+                }
+            }
+            // Read the sectors one by one to find which ones fail
+            Err(error) if error.kind() == ErrorKind::Io => break,
+            Err(error) => return Err(error),
+        }
+    }
+    report.sectors += count;
+    let mut data = Vec::with_capacity(count as usize * SECTOR_LEN);
+    for (i, sector_reads) in (0..).zip(&mut reads) {
+        while !sector_reads.is_verified(options.min_matches)
+            && sector_reads.reads() < options.max_reads.max(options.min_matches)
+        {
+            // Count each sector once, on its first extra read
+            if sector_reads.reads() == options.min_matches {
+                report.rereads += 1;
+            }
+            if options.defeat_cache {
+                defeat_cache(device, lba + i);
+            }
+            match read_sectors(device, lba + i, 1, report) {
+                Ok(sectors) => sector_reads.add(&sectors[0].data, &sectors[0].c2_errors),
+                Err(error) if error.kind() == ErrorKind::Io => sector_reads.add_failure(),
+                Err(error) => return Err(error),
+            }
+        }
+        if sector_reads.is_verified(options.min_matches) {
+            report.verified_sectors += 1;
+        } else {
+            let first_sample = first_sample + i as i64 * SAMPLES_PER_SECTOR as i64;
+            let suspicious_bytes = sector_reads.suspicious_bytes();
+            for (sample, bytes) in (first_sample..).zip(suspicious_bytes.chunks(4)) {
+                let in_track = u64::try_from(sample).is_ok_and(|sample| sample < track_samples);
+                if in_track && bytes.contains(&true) {
+                    report.add_suspicious_sample(sample as u64);
+                }
+            }
+        }
+        data.extend_from_slice(sector_reads.data());
+    }
+    Ok(data)
+}
+
+/// Reads audio sectors, with C2 error pointers if they're enabled in the report.
+fn read_sectors(
+    device: &Device,
+    lba: u32,
+    count: u32,
+    report: &mut SecureRipReport,
+) -> Result<Vec<CdSector>> {
+    if report.c2_used {
+        let options = ReadCdOptions {
+            c2_errors: C2ErrorInfo::Pointers,
+            ..ReadCdOptions::audio()
+        };
+        match device.read_cd(lba, count, options) {
+            // The drive rejected or ignored the request for C2 pointers
+            Err(error) if error.kind() == ErrorKind::UnsupportedOperation => {
+                report.c2_used = false;
+            }
+            result => return result,
+        }
+    }
+    device.read_cd(lba, count, ReadCdOptions::audio())
+}
+
+/// Reads a sector far from `lba`, so the drive replaces the sectors around `lba`
+/// in its cache and has to read them from the disc again.
+fn defeat_cache(device: &Device, lba: u32) {
+    let far_lba = lba
+        .checked_sub(CACHE_DISTANCE)
+        .unwrap_or(lba + CACHE_DISTANCE);
+    // Only the side effect matters, the sector may not even exist on short discs
+    let _ = device.read_cd(far_lba, 1, ReadCdOptions::audio());
+}
@@ -0,0 +1,38 @@
+use super::SAMPLE_RATE;
+use std::io::{self, Write};
+
+const CHANNELS: u16 = 2;
+const BITS_PER_SAMPLE: u16 = 16;
+const BLOCK_ALIGN: u16 = CHANNELS * BITS_PER_SAMPLE / 8;
+// Size of everything in the header after the RIFF chunk size
+const HEADER_REST_LEN: u32 = 36;
+
+/// Writes the 44 byte header of a WAV file with CD quality audio,
+/// to be followed by `data_len` bytes of PCM samples.
+///
+/// # Example
+///
+/// ```
+/// let mut wav = Vec::new();
+/// eject::audio::write_wav_header(&mut wav, 2352)?;
+/// assert_eq!(&wav[..4], b"RIFF");
+/// assert_eq!(wav.len(), 44);
+/// # std::io::Result::Ok(())
+/// ```
+pub fn write_wav_header(mut writer: impl Write, data_len: u32) -> io::Result<()> {
+    let mut header = Vec::with_capacity(44);
+    header.extend_from_slice(b"RIFF");
+    header.extend_from_slice(&data_len.saturating_add(HEADER_REST_LEN).to_le_bytes());
+    header.extend_from_slice(b"WAVEfmt ");
+    header.extend_from_slice(&16u32.to_le_bytes());
+    // Integer PCM
+    header.extend_from_slice(&1u16.to_le_bytes());
+    header.extend_from_slice(&CHANNELS.to_le_bytes());
+    header.extend_from_slice(&SAMPLE_RATE.to_le_bytes());
+    header.extend_from_slice(&(SAMPLE_RATE * BLOCK_ALIGN as u32).to_le_bytes());
+    header.extend_from_slice(&BLOCK_ALIGN.to_le_bytes());
+    header.extend_from_slice(&BITS_PER_SAMPLE.to_le_bytes());
+    header.extend_from_slice(b"data");
+    header.extend_from_slice(&data_len.to_le_bytes());
+    writer.write_all(&header)
+}
@@ -0,0 +1,21 @@
+/// Size of the inserted medium, returned by [`Device::capacity`][super::Device::capacity].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Capacity {
+    /// Number of logical blocks.
+    pub blocks: u64,
+    /// Size of each block in bytes, e.g. 2048 for CDs and DVDs or 512 for most USB sticks.
+    pub block_len: u32,
+}
+
+impl Capacity {
+    /// Creates a capacity with `blocks` blocks of `block_len` bytes each.
+    pub fn new(blocks: u64, block_len: u32) -> Self {
+        Self { blocks, block_len }
+    }
+
+    /// Returns the total size in bytes.
+    pub fn bytes(&self) -> u64 {
+        self.blocks * self.block_len as u64
+    }
+}
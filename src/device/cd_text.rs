@@ -0,0 +1,79 @@
+use std::collections::BTreeMap;
+
+/// CD-TEXT stored in the lead-in of a CD, returned by [`Device::cd_text`][super::Device::cd_text].
+///
+/// # Example
+///
+/// ```no_run
+/// use eject::device::Device;
+///
+/// let cd_text = Device::open("/dev/cdrom")?.cd_text()?;
+/// if let Some(block) = cd_text.blocks.first() {
+///     println!("{:?} by {:?}", block.album.title, block.album.performer);
+///     for (number, track) in &block.tracks {
+///         println!("{number}. {:?}", track.title);
+///     }
+/// }
+/// # eject::error::Result::Ok(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub struct CdText {
+    /// Blocks of text, each one in a different language. Usually there's only one.
+    pub blocks: Vec<CdTextBlock>,
+    /// Number of packs that were ignored because their CRC didn't match.
+    pub invalid_packs: usize,
+}
+
+impl CdText {
+    /// Returns whether the disc has no CD-TEXT.
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+}
+
+/// A block of [`CdText`] in one language.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub struct CdTextBlock {
+    /// Number of the block, from 0 to 7.
+    pub number: u8,
+    /// Language code defined by the EBU Tech 3258 standard, e.g. 0x09 for English.
+    pub language: u8,
+    /// Character set of the text: 0x00 for ISO 8859-1, 0x01 for ASCII or 0x80 for
+    /// MS-JIS. Text in other character sets is decoded as UTF-8.
+    pub character_code: u8,
+    /// Text about the whole disc. Its code is the UPC/EAN.
+    pub album: CdTextEntry,
+    /// Text about each track, by track number. Their codes are ISRCs.
+    pub tracks: BTreeMap<u8, CdTextEntry>,
+    /// Identifier of the disc given by its publisher.
+    pub disc_id: Option<String>,
+}
+
+impl CdTextBlock {
+    /// Returns the text about the track with this number, if there is any.
+    pub fn track(&self, number: u8) -> Option<&CdTextEntry> {
+        self.tracks.get(&number)
+    }
+}
+
+/// Text about a disc or a track, in a [`CdTextBlock`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub struct CdTextEntry {
+    /// Title of the album or track.
+    pub title: Option<String>,
+    /// Performers.
+    pub performer: Option<String>,
+    /// Songwriters.
+    pub songwriter: Option<String>,
+    /// Composers.
+    pub composer: Option<String>,
+    /// Arrangers.
+    pub arranger: Option<String>,
+    /// Message from the content provider or the artist.
+    pub message: Option<String>,
+    /// UPC/EAN of the album or ISRC of the track.
+    pub code: Option<String>,
+}
@@ -0,0 +1,62 @@
+use super::{Device, DriveStatus};
+use crate::error::Result;
+
+/// Controls a drive that holds several discs in slots, created with [`Device::changer`].
+///
+/// Slots are numbered from 0.
+///
+/// # Example
+///
+/// ```no_run
+/// use eject::device::Device;
+///
+/// let cdrom = Device::open("/dev/cdrom")?;
+/// if let Some(changer) = cdrom.changer()? {
+///     for slot in 0..changer.slots() {
+///         println!("Slot {slot}: {:?}", changer.slot_status(slot)?);
+///     }
+///     changer.load(1)?;
+/// }
+/// # eject::error::Result::Ok(())
+/// ```
+///
+/// # Platform specific behavior
+///
+/// **Linux:** This uses the `CDROM_CHANGER_NSLOTS`, `CDROM_DRIVE_STATUS` and
+/// `CDROM_SELECT_DISC` ioctls, and MMC commands if they fail.
+pub struct Changer<'a> {
+    device: &'a Device,
+    slots: usize,
+}
+
+impl<'a> Changer<'a> {
+    pub(crate) fn new(device: &'a Device, slots: usize) -> Self {
+        Self { device, slots }
+    }
+
+    /// Returns the number of slots.
+    pub fn slots(&self) -> usize {
+        self.slots
+    }
+
+    /// Gets the slot whose disc is in the drive, or was the last one loaded.
+    pub fn current_slot(&self) -> Result<usize> {
+        self.device.handle.current_slot()
+    }
+
+    /// Gets whether a slot has a disc. The status will be [`DriveStatus::Loaded`]
+    /// or [`DriveStatus::Empty`].
+    pub fn slot_status(&self, slot: usize) -> Result<DriveStatus> {
+        self.device.handle.slot_status(slot)
+    }
+
+    /// Moves the disc in a slot to the drive, unloading the current one first.
+    pub fn load(&self, slot: usize) -> Result<()> {
+        self.device.handle.load_slot(slot)
+    }
+
+    /// Returns the disc in the drive to its slot.
+    pub fn unload(&self) -> Result<()> {
+        self.device.handle.unload_slot()
+    }
+}
@@ -0,0 +1,185 @@
+use super::{toc::SESSION_GAP, Toc, TocTrack};
+use std::fmt::Write;
+
+// Disc IDs use addresses relative to the start of the lead-in, 2 seconds before LBA 0
+const LBA_OFFSET: u32 = 150;
+
+impl Toc {
+    /// Calculates the disc ID used by MusicBrainz to look up this disc.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use eject::device::Device;
+    ///
+    /// let toc = Device::open("/dev/cdrom")?.toc()?;
+    /// println!("https://musicbrainz.org/cdtoc/{}", toc.musicbrainz_id());
+    /// # eject::error::Result::Ok(())
+    /// ```
+    pub fn musicbrainz_id(&self) -> String {
+        let (tracks, lead_out) = self.musicbrainz_tracks();
+        let mut input = String::with_capacity(804);
+        let first = tracks
+            .first()
+            .map_or(self.first_track, |track| track.number);
+        let last = tracks.last().map_or(self.last_track, |track| track.number);
+        write!(input, "{first:02X}{last:02X}{:08X}", lead_out + LBA_OFFSET).unwrap();
+        for number in 1..=99 {
+            let offset = tracks
+                .iter()
+                .find(|track| track.number == number)
+                .map_or(0, |track| track.start_lba + LBA_OFFSET);
+            write!(input, "{offset:08X}").unwrap();
+        }
+        musicbrainz_base64(&sha1(input.as_bytes()))
+    }
+
+    /// Returns the TOC in the format used by MusicBrainz, which can be sent to
+    /// its web service to find discs with a similar TOC.
+    ///
+    /// It contains the first and last track numbers, the lead-out address and the
+    /// address of every track, separated by spaces. Addresses include the 150
+    /// sectors before LBA 0.
+    pub fn musicbrainz_toc(&self) -> String {
+        let (tracks, lead_out) = self.musicbrainz_tracks();
+        let first = tracks
+            .first()
+            .map_or(self.first_track, |track| track.number);
+        let last = tracks.last().map_or(self.last_track, |track| track.number);
+        let mut toc = format!("{first} {last} {}", lead_out + LBA_OFFSET);
+        for track in tracks {
+            write!(toc, " {}", track.start_lba + LBA_OFFSET).unwrap();
+        }
+        toc
+    }
+
+    /// Calculates the 32-bit disc ID used by freedb and other CDDB servers.
+    pub fn freedb_id(&self) -> u32 {
+        let seconds = |lba: u32| (lba + LBA_OFFSET) / 75;
+        let digit_sum: u32 = self
+            .tracks
+            .iter()
+            .map(|track| {
+                let mut seconds = seconds(track.start_lba);
+                let mut sum = 0;
+                while seconds > 0 {
+                    sum += seconds % 10;
+                    seconds /= 10;
+                }
+                sum
+            })
+            .sum();
+        let first_start = self.tracks.first().map_or(0, |track| track.start_lba);
+        let length = seconds(self.lead_out_lba).saturating_sub(seconds(first_start));
+        (digit_sum % 0xff) << 24 | length << 8 | self.tracks.len() as u32
+    }
+
+    /// Returns the command used to look up this disc on a CDDB server.
+    ///
+    /// It contains the disc ID, the number of tracks, the address of every track
+    /// in frames and the length of the disc in seconds.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use eject::device::Device;
+    ///
+    /// let toc = Device::open("/dev/cdrom")?.toc()?;
+    /// // e.g. "cddb query 08001a02 2 150 1150 28"
+    /// println!("{}", toc.cddb_query());
+    /// # eject::error::Result::Ok(())
+    /// ```
+    pub fn cddb_query(&self) -> String {
+        let mut query = format!("cddb query {:08x} {}", self.freedb_id(), self.tracks.len());
+        for track in &self.tracks {
+            write!(query, " {}", track.start_lba + LBA_OFFSET).unwrap();
+        }
+        write!(query, " {}", (self.lead_out_lba + LBA_OFFSET) / 75).unwrap();
+        query
+    }
+
+    /// Returns the tracks and the lead-out used for MusicBrainz, which ignores
+    /// the data track at the end of enhanced CDs.
+    fn musicbrainz_tracks(&self) -> (&[TocTrack], u32) {
+        match self.tracks.as_slice() {
+            [tracks @ .., last]
+                if last.control.is_data()
+                    && tracks.last().is_some_and(|track| track.control.is_audio()) =>
+            {
+                (tracks, last.start_lba.saturating_sub(SESSION_GAP))
+            }
+            tracks => (tracks, self.lead_out_lba),
+        }
+    }
+}
+
+/// Encodes data with the variant of base64 used by MusicBrainz, which
+/// uses `.`, `_` and `-` instead of `+`, `/` and `=`.
+fn musicbrainz_base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789._";
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let bits = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(bits >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('-');
+            }
+        }
+    }
+    encoded
+}
+
+/// Calculates the SHA-1 hash of some data.
+fn sha1(data: &[u8]) -> [u8; 20] {
+    let mut state: [u32; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+    let mut message = data.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&(data.len() as u64 * 8).to_be_bytes());
+    for block in message.chunks_exact(64) {
+        let mut words = [0u32; 80];
+        for (word, bytes) in words.iter_mut().zip(block.chunks_exact(4)) {
+            *word = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+        for i in 16..80 {
+            words[i] = (words[i - 3] ^ words[i - 8] ^ words[i - 14] ^ words[i - 16]).rotate_left(1);
+        }
+        let [mut a, mut b, mut c, mut d, mut e] = state;
+        for (i, word) in words.iter().enumerate() {
+            let (f, k) = match i {
+                0..=19 => ((b & c) | (!b & d), 0x5a827999),
+                20..=39 => (b ^ c ^ d, 0x6ed9eba1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8f1bbcdc),
+                _ => (b ^ c ^ d, 0xca62c1d6),
+            };
+            let temp = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(*word);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+        }
+        for (value, new) in state.iter_mut().zip([a, b, c, d, e]) {
+            *value = value.wrapping_add(new);
+        }
+    }
+    let mut hash = [0; 20];
+    for (bytes, value) in hash.chunks_exact_mut(4).zip(state) {
+        bytes.copy_from_slice(&value.to_be_bytes());
+    }
+    hash
+}
@@ -0,0 +1,89 @@
+/// Recording state of a disc, returned by [`Device::disc_info`][super::Device::disc_info].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct DiscInfo {
+    /// Whether the disc is blank, can be appended to or is finalized.
+    pub status: DiscStatus,
+    /// State of the last session.
+    pub last_session_state: SessionState,
+    /// Whether the disc is rewritable, e.g. a CD-RW.
+    pub erasable: bool,
+    /// Number of sessions, including the incomplete one if there is.
+    pub sessions: u16,
+    /// Number of the first track on the disc.
+    pub first_track: u16,
+    /// Number of the first track in the last session.
+    pub first_track_in_last_session: u16,
+    /// Number of the last track in the last session.
+    pub last_track_in_last_session: u16,
+    /// Type of CD. Not meaningful for other media.
+    pub disc_type: CdDiscType,
+    /// Logical block address after which no more data can be written,
+    /// or `None` if the disc can't be written to.
+    pub last_possible_lead_out_lba: Option<u32>,
+}
+
+impl DiscInfo {
+    /// Returns whether nothing has been written to the disc.
+    pub fn is_blank(&self) -> bool {
+        self.status == DiscStatus::Empty
+    }
+
+    /// Returns whether more data can be written to the disc.
+    pub fn is_appendable(&self) -> bool {
+        matches!(self.status, DiscStatus::Empty | DiscStatus::Incomplete)
+    }
+}
+
+/// Recording status of a disc.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DiscStatus {
+    /// Nothing has been written to the disc.
+    Empty,
+    /// The disc can be appended to.
+    Incomplete,
+    /// The disc is finalized, or is read only.
+    Complete,
+    /// The disc is random access, e.g. a formatted DVD-RAM or BD-RE.
+    Other,
+}
+
+/// Recording state of a session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SessionState {
+    /// The session is empty.
+    Empty,
+    /// The session is open and data can be appended to it.
+    Incomplete,
+    /// The session is damaged and can't be appended to.
+    Damaged,
+    /// The session is closed.
+    Complete,
+}
+
+/// Type of CD as reported by READ DISC INFORMATION.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CdDiscType {
+    /// Audio CD or CD-ROM.
+    CdDaOrCdRom,
+    /// CD-I disc.
+    CdI,
+    /// CD-ROM XA disc.
+    CdRomXa,
+    /// The type is not defined, e.g. because the disc is blank.
+    Undefined,
+    /// Any other type, with its MMC code.
+    Other(u8),
+}
+
+impl From<u8> for CdDiscType {
+    fn from(code: u8) -> Self {
+        match code {
+            0x00 => Self::CdDaOrCdRom,
+            0x10 => Self::CdI,
+            0x20 => Self::CdRomXa,
+            0xff => Self::Undefined,
+            code => Self::Other(code),
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use super::{Device, DriveStatus};
+use crate::{error::Result, mmc};
+use std::{
+    collections::VecDeque,
+    thread::sleep,
+    time::{Duration, Instant},
+};
+
+const DEFAULT_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Something that happened to a drive, reported by [`Events`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeviceEvent {
+    /// The tray was opened.
+    TrayOpened,
+    /// The tray was closed.
+    TrayClosed,
+    /// A medium was inserted.
+    MediumInserted,
+    /// The medium was removed.
+    MediumRemoved,
+    /// The inserted medium can be read now.
+    Ready,
+    /// The eject button was pressed. Drives only report this while
+    /// the ejection is locked, otherwise they eject on their own.
+    EjectRequested,
+}
+
+/// Watches a drive for changes, created with [`Device::events`].
+///
+/// The drive is polled every 500 ms by default. Changes in its status are
+/// reported once, in the order they happen.
+///
+/// # Example
+///
+/// ```no_run
+/// use eject::device::{Device, DeviceEvent};
+///
+/// let cdrom = Device::open("/dev/cdrom")?;
+/// for event in cdrom.events() {
+///     if event? == DeviceEvent::Ready {
+///         println!("A disc is ready to be read");
+///         break;
+///     }
+/// }
+/// # eject::error::Result::Ok(())
+/// ```
+///
+/// # Platform specific behavior
+///
+/// **Linux:** This also uses the `CDROM_MEDIA_CHANGED` ioctl to notice discs
+/// that were replaced between polls.
+pub struct Events<'a> {
+    device: &'a Device,
+    interval: Duration,
+    next_poll: Instant,
+    tracker: EventTracker,
+    pending: VecDeque<DeviceEvent>,
+}
+
+impl<'a> Events<'a> {
+    pub(crate) fn new(device: &'a Device) -> Self {
+        Self {
+            device,
+            interval: DEFAULT_INTERVAL,
+            next_poll: Instant::now(),
+            tracker: EventTracker::default(),
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Sets how often the drive is polled.
+    pub fn set_interval(&mut self, interval: Duration) -> &mut Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Returns the next event without waiting, or `None` if nothing happened since the last poll.
+    ///
+    /// The drive is only polled again once the interval has passed.
+    pub fn try_next(&mut self) -> Result<Option<DeviceEvent>> {
+        if self.pending.is_empty() && Instant::now() >= self.next_poll {
+            self.next_poll = Instant::now() + self.interval;
+            let observation = self.observe()?;
+            self.tracker.update(&observation, &mut self.pending);
+        }
+        Ok(self.pending.pop_front())
+    }
+
+    fn observe(&self) -> Result<Observation> {
+        let handle = &self.device.handle;
+        // Get the media event before the status. Reading the status may also
+        // poll the media event class, which clears the pending event, so
+        // changes and eject requests would be lost if it went first.
+        let media_event = mmc::events::media_event(handle).ok();
+        let status = handle.status()?;
+        Ok(Observation {
+            status,
+            present: media_event.map(|event| event.present),
+            eject_requested: media_event.is_some_and(|event| event.eject_requested),
+            changed: media_event.is_some_and(|event| event.changed)
+                || handle.media_changed().unwrap_or(false),
+        })
+    }
+}
+
+impl Iterator for Events<'_> {
+    type Item = Result<DeviceEvent>;
+
+    /// Waits for the next event. This never returns `None`.
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.try_next() {
+                Ok(Some(event)) => return Some(Ok(event)),
+                Ok(None) => sleep(self.next_poll.saturating_duration_since(Instant::now())),
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
+/// What was found out about a drive in one poll.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Observation {
+    pub status: DriveStatus,
+    /// Whether there's a medium, if the drive reports it.
+    pub present: Option<bool>,
+    pub eject_requested: bool,
+    /// The medium was replaced since the last poll.
+    pub changed: bool,
+}
+
+/// Turns successive observations of a drive into events.
+#[derive(Debug, Default)]
+pub(crate) struct EventTracker {
+    // Last status and whether there was a medium
+    last: Option<(DriveStatus, bool)>,
+}
+
+impl EventTracker {
+    pub fn update(&mut self, observation: &Observation, events: &mut VecDeque<DeviceEvent>) {
+        let status = observation.status;
+        let present = observation.present.unwrap_or(match status {
+            DriveStatus::Loaded => true,
+            DriveStatus::Empty | DriveStatus::TrayOpen => false,
+            // It may still be spinning up or checking the medium
+            DriveStatus::NotReady => self.last.is_some_and(|(_, present)| present),
+        });
+        let Some((last_status, last_present)) = self.last.replace((status, present)) else {
+            // Nothing to compare with the first time
+            return;
+        };
+        if observation.eject_requested {
+            events.push_back(DeviceEvent::EjectRequested);
+        }
+        let swapped = last_present && present && observation.changed;
+        if last_present && (!present || swapped) {
+            events.push_back(DeviceEvent::MediumRemoved);
+        }
+        match (last_status.tray_open(), status.tray_open()) {
+            (false, true) => events.push_back(DeviceEvent::TrayOpened),
+            (true, false) => events.push_back(DeviceEvent::TrayClosed),
+            _ => {}
+        }
+        if present && (!last_present || swapped) {
+            events.push_back(DeviceEvent::MediumInserted);
+        }
+        if status == DriveStatus::Loaded && (last_status != DriveStatus::Loaded || swapped) {
+            events.push_back(DeviceEvent::Ready);
+        }
+    }
+}
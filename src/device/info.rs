@@ -0,0 +1,139 @@
+use std::fmt;
+
+/// Identification data of a device, returned by [`Device::info`][super::Device::info].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct DeviceInfo {
+    /// Name of the manufacturer, e.g. `HL-DT-ST`.
+    pub vendor: String,
+    /// Model of the device, e.g. `DVDRAM GP57EB40`.
+    pub product: String,
+    /// Firmware revision.
+    pub revision: String,
+    /// What kind of device this is.
+    pub device_type: DeviceType,
+    /// Whether the medium can be removed from the device.
+    pub removable: bool,
+    /// Unit serial number, if the device reports it.
+    pub serial_number: Option<String>,
+    /// Unique identifiers of the device, if it reports them.
+    pub identifiers: Vec<DeviceIdentifier>,
+}
+
+/// Type of a SCSI device as reported by its peripheral device type field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DeviceType {
+    /// Hard drives, USB sticks, card readers and other block devices.
+    DirectAccess,
+    /// Tape drives.
+    SequentialAccess,
+    /// Write once devices.
+    WriteOnce,
+    /// CD, DVD and Blu-ray drives.
+    CdDvd,
+    /// Magneto-optical drives.
+    OpticalMemory,
+    /// Tape libraries and disc changers.
+    MediumChanger,
+    /// Simplified direct access devices, e.g. some card readers.
+    SimplifiedDirectAccess,
+    /// Any other type, with its SCSI code.
+    Other(u8),
+}
+
+impl From<u8> for DeviceType {
+    /// Converts the low 5 bits of `code` to a device type.
+    fn from(code: u8) -> Self {
+        match code & 0x1f {
+            0x00 => Self::DirectAccess,
+            0x01 => Self::SequentialAccess,
+            0x04 => Self::WriteOnce,
+            0x05 => Self::CdDvd,
+            0x07 => Self::OpticalMemory,
+            0x08 => Self::MediumChanger,
+            0x0e => Self::SimplifiedDirectAccess,
+            code => Self::Other(code),
+        }
+    }
+}
+
+/// A unique identifier of a device, from the device identification VPD page.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct DeviceIdentifier {
+    /// Format of the identifier.
+    pub kind: IdentifierKind,
+    /// What the identifier refers to.
+    pub association: IdentifierAssociation,
+    /// Whether [`value`][Self::value] contains text.
+    pub is_text: bool,
+    /// The identifier as sent by the device.
+    pub value: Vec<u8>,
+}
+
+impl fmt::Display for DeviceIdentifier {
+    /// Writes the identifier as text if it is, otherwise as hexadecimal.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_text {
+            write!(f, "{}", String::from_utf8_lossy(&self.value).trim_end())
+        } else {
+            self.value.iter().try_for_each(|b| write!(f, "{b:02x}"))
+        }
+    }
+}
+
+/// Format of a [`DeviceIdentifier`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum IdentifierKind {
+    /// Vendor specific format.
+    VendorSpecific,
+    /// T10 vendor identification followed by a vendor specific identifier.
+    T10VendorId,
+    /// IEEE EUI-64 identifier.
+    Eui64,
+    /// Name Address Authority identifier, e.g. a World Wide Name.
+    Naa,
+    /// SCSI name string.
+    ScsiName,
+    /// Any other format, with its SCSI code.
+    Other(u8),
+}
+
+impl From<u8> for IdentifierKind {
+    /// Converts the low 4 bits of `code` to an identifier kind.
+    fn from(code: u8) -> Self {
+        match code & 0x0f {
+            0x0 => Self::VendorSpecific,
+            0x1 => Self::T10VendorId,
+            0x2 => Self::Eui64,
+            0x3 => Self::Naa,
+            0x8 => Self::ScsiName,
+            code => Self::Other(code),
+        }
+    }
+}
+
+/// Entity a [`DeviceIdentifier`] is associated with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum IdentifierAssociation {
+    /// The addressed logical unit, i.e. the drive itself.
+    LogicalUnit,
+    /// The port the command was received on.
+    TargetPort,
+    /// The device that contains the logical unit.
+    TargetDevice,
+    /// Reserved value.
+    Reserved,
+}
+
+impl From<u8> for IdentifierAssociation {
+    /// Converts the low 2 bits of `code` to an association.
+    fn from(code: u8) -> Self {
+        match code & 0x03 {
+            0 => Self::LogicalUnit,
+            1 => Self::TargetPort,
+            2 => Self::TargetDevice,
+            _ => Self::Reserved,
+        }
+    }
+}
@@ -0,0 +1,115 @@
+/// Loading mechanism and capabilities of a drive, returned by
+/// [`Device::mechanism`][super::Device::mechanism].
+///
+/// # Example
+///
+/// ```no_run
+/// use eject::device::Device;
+///
+/// let cdrom = Device::open("/dev/cdrom")?;
+/// let mechanism = cdrom.mechanism()?;
+/// if mechanism.can_retract {
+///     cdrom.retract()?;
+/// }
+/// if mechanism.write.cd_rw {
+///     println!("This drive can burn CD-RWs");
+/// }
+/// # eject::error::Result::Ok(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Mechanism {
+    /// How discs are inserted.
+    pub loading: LoadingMechanism,
+    /// Whether the medium can be ejected with a command.
+    pub can_eject: bool,
+    /// Whether the tray can be closed with a command. Drives don't report this,
+    /// so it's guessed from the [`loading`][Self::loading] mechanism.
+    pub can_retract: bool,
+    /// Whether the ejection can be locked.
+    pub can_lock: bool,
+    /// Whether the ejection is currently locked.
+    pub locked: bool,
+    /// Whether the drive has a jumper that locks the ejection when it starts.
+    pub prevent_jumper: bool,
+    /// Media the drive can read, besides CD-ROMs.
+    pub read: MediaSupport,
+    /// Media the drive can write.
+    pub write: MediaSupport,
+    /// Support for audio CDs.
+    pub audio: AudioCapabilities,
+    /// Maximum read speed in kB/s, if the drive reports it.
+    pub max_read_speed: Option<u32>,
+    /// Maximum write speed in kB/s, if the drive reports it.
+    pub max_write_speed: Option<u32>,
+}
+
+/// How discs are inserted in a drive, in a [`Mechanism`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LoadingMechanism {
+    /// Discs are inserted in a caddy, which is then inserted in the drive.
+    Caddy,
+    /// A motorized tray.
+    Tray,
+    /// A tray that pops up and must be closed by hand.
+    PopUp,
+    /// A changer where discs are inserted one by one.
+    Changer,
+    /// A changer where discs are inserted in a cartridge.
+    Cartridge,
+    /// The drive doesn't report its loading mechanism.
+    Unknown,
+    /// Any other mechanism, with its MMC code.
+    Other(u8),
+}
+
+impl From<u8> for LoadingMechanism {
+    fn from(code: u8) -> Self {
+        match code {
+            0 => Self::Caddy,
+            1 => Self::Tray,
+            2 => Self::PopUp,
+            4 => Self::Changer,
+            5 => Self::Cartridge,
+            code => Self::Other(code),
+        }
+    }
+}
+
+/// Types of media that a drive can read or write, in a [`Mechanism`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub struct MediaSupport {
+    /// CD-R discs.
+    pub cd_r: bool,
+    /// CD-RW discs.
+    pub cd_rw: bool,
+    /// DVD-ROM discs. Always false for writing.
+    pub dvd_rom: bool,
+    /// DVD-R discs.
+    pub dvd_r: bool,
+    /// DVD-RAM discs.
+    pub dvd_ram: bool,
+}
+
+/// Support for audio CDs, in a [`Mechanism`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub struct AudioCapabilities {
+    /// Whether the drive can play audio through its outputs.
+    pub play: bool,
+    /// Whether audio can be read with READ CD.
+    pub read_cdda: bool,
+    /// Whether audio reads start exactly where requested, so they don't need to overlap.
+    pub accurate_stream: bool,
+    /// Whether the drive reports C2 error pointers.
+    pub c2_pointers: bool,
+    /// Whether the drive can read ISRCs.
+    pub isrc: bool,
+    /// Whether the drive can read the Media Catalog Number.
+    pub mcn: bool,
+    /// Whether each channel can have a different volume.
+    pub separate_volume: bool,
+    /// Number of volume levels, if the drive reports it.
+    pub volume_levels: Option<u16>,
+}
@@ -1,14 +1,53 @@
 //! Interact with a specific device.
 
+mod capacity;
+mod cd_text;
+mod changer;
+mod disc_id;
+mod disc_info;
+mod events;
+mod info;
+mod mechanism;
+mod playback;
+mod profile;
+mod read_cd;
+mod sectors;
+mod speed;
 mod status;
+mod toc;
 #[cfg(unix)]
 mod unix_trait_impls;
 #[cfg(windows)]
 mod windows_trait_impls;
 
-pub use self::status::DriveStatus;
-use crate::{error::Result, platform::device::DeviceHandle};
-use std::{path::Path, time::Instant};
+pub use self::{
+    capacity::Capacity,
+    cd_text::{CdText, CdTextBlock, CdTextEntry},
+    changer::Changer,
+    disc_info::{CdDiscType, DiscInfo, DiscStatus, SessionState},
+    events::{DeviceEvent, Events},
+    info::{DeviceIdentifier, DeviceInfo, DeviceType, IdentifierAssociation, IdentifierKind},
+    mechanism::{AudioCapabilities, LoadingMechanism, Mechanism, MediaSupport},
+    playback::{AudioState, Playback, PlaybackStatus, Volume},
+    profile::{DriveFeatures, Feature, Profile},
+    read_cd::{C2ErrorInfo, CdSector, HeaderCodes, ReadCdOptions, SectorType, Subchannel},
+    sectors::{ReadError, Sectors},
+    speed::DriveSpeeds,
+    status::{DriveStatus, PowerState, StatusCondition, StatusDetails},
+    toc::{DiscKind, LastSession, Msf, Toc, TocTrack, TrackControl},
+};
+#[cfg(test)]
+pub(crate) use self::{
+    events::{EventTracker, Observation},
+    status::{retracted, wait_for},
+};
+use crate::{
+    error::{Error, ErrorKind, Result},
+    mmc,
+    platform::device::DeviceHandle,
+    scsi::{ScsiCommand, ScsiResponse},
+};
+use std::{path::Path, time::Duration};
 
 /// A reference to a device that can be used to send commands.
 ///
@@ -55,31 +94,50 @@ impl Device {
         self.handle.retract()
     }
 
+    /// Ejects the medium and waits until the tray is open.
+    ///
+    /// Drives without a tray, like slot-loading ones, never report it as open.
+    /// With those use [`eject`][Self::eject] and wait for [`DriveStatus::Empty`] instead.
+    pub fn eject_and_wait(&self, timeout: Duration) -> Result<()> {
+        self.eject()?;
+        self.wait_for(DriveStatus::TrayOpen, timeout)?;
+        Ok(())
+    }
+
+    /// Closes the tray and waits until the drive is ready, returning whether
+    /// it's [`Loaded`][DriveStatus::Loaded] or [`Empty`][DriveStatus::Empty].
+    pub fn retract_and_wait(&self, timeout: Duration) -> Result<DriveStatus> {
+        self.retract()?;
+        self.wait_for(status::retracted, timeout)
+    }
+
     /// Opens the tray if it's closed, closes it if it's open.
     ///
-    /// Returns true after opening and false after closing.
+    /// Returns true after opening and false after closing. Fails if the position of
+    /// the tray can't be determined, see [`tray_open`][Self::tray_open].
     pub fn toggle_eject(&self) -> Result<bool> {
-        if let Ok(status) = self.status() {
-            if status.tray_open() {
-                self.retract()?;
-                Ok(false)
-            } else {
-                self.eject()?;
-                Ok(true)
-            }
+        if self.tray_open()? {
+            self.retract()?;
+            Ok(false)
         } else {
-            let time = Instant::now();
             self.eject()?;
-            if time.elapsed().as_millis() < 100 {
-                // If it was too fast it was already open
-                self.retract()?;
-                Ok(false)
-            } else {
-                Ok(true)
-            }
+            Ok(true)
         }
     }
 
+    /// Returns whether the tray of this drive is open.
+    ///
+    /// This uses MECHANISM STATUS, or GET EVENT STATUS NOTIFICATION if the
+    /// drive doesn't support it. If neither works an error of kind
+    /// [`ErrorKind::UnsupportedOperation`] is returned.
+    ///
+    /// # Platform specific behavior
+    ///
+    /// **Linux:** This uses the `CDROM_DRIVE_STATUS` ioctl first.
+    pub fn tray_open(&self) -> Result<bool> {
+        self.handle.tray_open()
+    }
+
     /// Prevents the medium from being ejected, even if the eject button is pressed.
     ///
     /// In case of success returns an [`EjectionLock`] that will release the lock when dropped.
@@ -88,16 +146,398 @@ impl Device {
     ///
     /// **Linux:** Many distros are configured in a way that makes this call useless.
     /// See <https://unix.stackexchange.com/a/104935>
-    pub fn lock_ejection(&self) -> Result<EjectionLock> {
+    pub fn lock_ejection(&self) -> Result<EjectionLock<'_>> {
         self.handle.set_ejection_lock(true)?;
         Ok(EjectionLock { device: self })
     }
 
     /// Gets the position of the tray (if it exists) and whether
     /// there's data loaded in this drive.
+    ///
+    /// # Platform specific behavior
+    ///
+    /// **Linux:** This uses the `CDROM_DRIVE_STATUS` ioctl. If the driver doesn't
+    /// support it, as happens with USB devices handled by `sd`, the status is
+    /// read with GET EVENT STATUS NOTIFICATION, or TEST UNIT READY for devices
+    /// that aren't optical drives. Not all of them tell whether the tray is open.
     pub fn status(&self) -> Result<DriveStatus> {
         self.handle.status()
     }
+
+    /// Waits until the status of this drive meets a condition, which can be a
+    /// [`DriveStatus`] or a function, and returns the status.
+    ///
+    /// If it isn't met before the timeout, returns an error of kind
+    /// [`ErrorKind::TimedOut`] whose [`status`][Error::status] is the last one observed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use eject::device::{Device, DriveStatus};
+    /// use std::time::Duration;
+    ///
+    /// let cdrom = Device::open("/dev/cdrom")?;
+    /// // Wait for the user to insert a disc
+    /// cdrom.wait_for(DriveStatus::Loaded, Duration::from_secs(60))?;
+    /// // Wait for the user to open the tray or remove the disc
+    /// cdrom.wait_for(|status| status != DriveStatus::Loaded, Duration::from_secs(60))?;
+    /// # eject::error::Result::Ok(())
+    /// ```
+    pub fn wait_for(
+        &self,
+        condition: impl StatusCondition,
+        timeout: Duration,
+    ) -> Result<DriveStatus> {
+        status::wait_for(|| self.status(), condition, timeout)
+    }
+
+    /// Waits until the drive stops being [`NotReady`][DriveStatus::NotReady],
+    /// which happens for a few seconds after a disc is inserted, and returns its status.
+    pub fn wait_ready(&self, timeout: Duration) -> Result<DriveStatus> {
+        self.wait_for(|status| status != DriveStatus::NotReady, timeout)
+    }
+
+    /// Gets the status of this drive along with its power state and whether it's busy.
+    ///
+    /// This requires a drive that supports GET EVENT STATUS NOTIFICATION, as almost
+    /// every CD, DVD and Blu-ray drive does.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use eject::device::{Device, PowerState};
+    ///
+    /// let details = Device::open("/dev/cdrom")?.status_details()?;
+    /// if details.power == Some(PowerState::Standby) {
+    ///     println!("The disc isn't spinning");
+    /// }
+    /// # eject::error::Result::Ok(())
+    /// ```
+    pub fn status_details(&self) -> Result<StatusDetails> {
+        mmc::events::status_details(&self.handle)
+    }
+
+    /// Creates a controller for the slots of a disc changer,
+    /// or returns `None` if this drive only holds one disc.
+    ///
+    /// See [`Changer`] for an example.
+    pub fn changer(&self) -> Result<Option<Changer<'_>>> {
+        let slots = self.handle.changer_slots()?;
+        Ok((slots > 1).then(|| Changer::new(self, slots)))
+    }
+
+    /// Creates an iterator over the changes of this drive, like the tray being
+    /// opened or a disc being inserted.
+    ///
+    /// See [`Events`] for an example.
+    pub fn events(&self) -> Events<'_> {
+        Events::new(self)
+    }
+
+    /// Gets the vendor, model and other identification data of this drive.
+    ///
+    /// The serial number and identifiers are only filled in if the drive reports them.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use eject::device::Device;
+    ///
+    /// let info = Device::open("/dev/cdrom")?.info()?;
+    /// println!("{} {} ({})", info.vendor, info.product, info.revision);
+    /// # eject::error::Result::Ok(())
+    /// ```
+    pub fn info(&self) -> Result<DeviceInfo> {
+        mmc::inquiry::info(&self.handle)
+    }
+
+    /// Gets how discs are loaded in this drive, whether it can retract and lock,
+    /// and which media it can read and write.
+    ///
+    /// See [`Mechanism`] for an example.
+    ///
+    /// # Platform specific behavior
+    ///
+    /// **Linux:** If the drive doesn't accept SCSI commands this uses the
+    /// `CDROM_GET_CAPABILITY` ioctl, which doesn't report the lock state,
+    /// speeds and most audio capabilities.
+    pub fn mechanism(&self) -> Result<Mechanism> {
+        self.handle.mechanism()
+    }
+
+    /// Gets the type of the inserted medium, or `None` if the drive is empty.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use eject::device::{Device, Profile};
+    ///
+    /// let cdrom = Device::open("/dev/cdrom")?;
+    /// match cdrom.media_profile()? {
+    ///     Some(profile) if profile.is_cd() => println!("It's a CD"),
+    ///     Some(profile) if profile.is_dvd() => println!("It's a DVD"),
+    ///     Some(profile) if profile.is_bd() => println!("It's a Blu-ray"),
+    ///     Some(profile) => println!("It's something else: {profile:?}"),
+    ///     None => println!("There's no disc"),
+    /// }
+    /// # eject::error::Result::Ok(())
+    /// ```
+    pub fn media_profile(&self) -> Result<Option<Profile>> {
+        mmc::configuration::media_profile(&self.handle)
+    }
+
+    /// Gets the profiles (types of media) and features supported by this drive.
+    pub fn features(&self) -> Result<DriveFeatures> {
+        mmc::configuration::features(&self.handle)
+    }
+
+    /// Reads the table of contents of the inserted CD.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use eject::device::Device;
+    ///
+    /// let toc = Device::open("/dev/cdrom")?.toc()?;
+    /// for track in &toc.tracks {
+    ///     let kind = if track.control.is_audio() { "audio" } else { "data" };
+    ///     println!("Track {}: {kind}, starts at {}", track.number, track.start_msf);
+    /// }
+    /// # eject::error::Result::Ok(())
+    /// ```
+    ///
+    /// # Platform specific behavior
+    ///
+    /// **Linux:** If the drive doesn't accept SCSI commands, the TOC is read with
+    /// the `CDROMREADTOCENTRY` ioctl. In that case only the tracks of the last
+    /// session are told apart, reported as part of the second session, while
+    /// the rest will be reported as part of the first one.
+    pub fn toc(&self) -> Result<Toc> {
+        self.handle.toc()
+    }
+
+    /// Reads the CD-TEXT of the inserted CD, which will be empty if it has none.
+    ///
+    /// See [`CdText`] for an example.
+    pub fn cd_text(&self) -> Result<CdText> {
+        mmc::cd_text::read_cd_text(&self.handle)
+    }
+
+    /// Reads the Media Catalog Number of the inserted CD, which is usually its UPC/EAN
+    /// barcode, or `None` if it doesn't have one.
+    ///
+    /// # Platform specific behavior
+    ///
+    /// **Linux:** If the drive doesn't accept SCSI commands this uses the
+    /// `CDROM_GET_MCN` ioctl.
+    pub fn mcn(&self) -> Result<Option<String>> {
+        self.handle.mcn()
+    }
+
+    /// Reads the International Standard Recording Code of a track,
+    /// or `None` if it doesn't have one.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use eject::device::Device;
+    ///
+    /// let cdrom = Device::open("/dev/cdrom")?;
+    /// for track in cdrom.toc()?.tracks {
+    ///     if let Some(isrc) = cdrom.isrc(track.number)? {
+    ///         println!("{}: {isrc}", track.number);
+    ///     }
+    /// }
+    /// # eject::error::Result::Ok(())
+    /// ```
+    pub fn isrc(&self, track: u8) -> Result<Option<String>> {
+        mmc::subchannel::read_isrc(&self.handle, track)
+    }
+
+    /// Gets the type of the tracks on the inserted CD.
+    ///
+    /// The type is found out from the TOC, so data tracks are reported as
+    /// [`DiscKind::DataMode1`], or [`DiscKind::XaMode2Form1`] on CD-ROM XA discs.
+    /// On every platform, [`DiscKind::DataMode2`] and [`DiscKind::XaMode2Form2`]
+    /// are only returned by the Linux fallback below.
+    ///
+    /// # Platform specific behavior
+    ///
+    /// **Linux:** If the drive doesn't accept SCSI commands this uses the
+    /// `CDROM_DISC_STATUS` ioctl, which reads the headers of data tracks to tell their mode.
+    pub fn disc_kind(&self) -> Result<DiscKind> {
+        self.handle.disc_kind()
+    }
+
+    /// Gets where the last session of the inserted CD starts.
+    ///
+    /// # Platform specific behavior
+    ///
+    /// **Linux:** If the drive doesn't accept SCSI commands this uses the
+    /// `CDROMMULTISESSION` ioctl.
+    pub fn last_session(&self) -> Result<LastSession> {
+        self.handle.last_session()
+    }
+
+    /// Gets whether the inserted disc is blank, can be appended to or is finalized,
+    /// along with its number of sessions and other recording information.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use eject::device::Device;
+    ///
+    /// let info = Device::open("/dev/cdrom")?.disc_info()?;
+    /// if info.erasable && !info.is_blank() {
+    ///     println!("This disc must be blanked before writing to it");
+    /// }
+    /// # eject::error::Result::Ok(())
+    /// ```
+    pub fn disc_info(&self) -> Result<DiscInfo> {
+        mmc::disc_info::disc_info(&self.handle)
+    }
+
+    /// Gets the number of blocks and the block size of the inserted medium.
+    ///
+    /// This works with optical discs as well as USB sticks and card readers.
+    /// Audio CDs don't have a block size and many drives report 2048 for them.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use eject::device::Device;
+    ///
+    /// let capacity = Device::open("/dev/cdrom")?.capacity()?;
+    /// println!("{} MB", capacity.bytes() / 1_000_000);
+    /// # eject::error::Result::Ok(())
+    /// ```
+    ///
+    /// # Platform specific behavior
+    ///
+    /// **Linux:** If the device doesn't accept SCSI commands this uses the
+    /// `BLKGETSIZE64` and `BLKSSZGET` ioctls.
+    pub fn capacity(&self) -> Result<Capacity> {
+        self.handle.capacity()
+    }
+
+    /// Limits the read and write speed of the drive, in kB/s.
+    ///
+    /// The drive will use the fastest speed it supports that's not faster than these.
+    /// Use `u32::MAX` to select the fastest speed. A slower speed makes the drive
+    /// quieter, which is useful when playing audio.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use eject::device::Device;
+    ///
+    /// let cdrom = Device::open("/dev/cdrom")?;
+    /// // Read CDs at 4x
+    /// cdrom.set_speed(4 * 176, u32::MAX)?;
+    /// # eject::error::Result::Ok(())
+    /// ```
+    ///
+    /// # Platform specific behavior
+    ///
+    /// **Linux:** If the drive doesn't accept SCSI commands this uses the
+    /// `CDROM_SELECT_SPEED` ioctl, which only sets the read speed of CD drives.
+    pub fn set_speed(&self, read_kbps: u32, write_kbps: u32) -> Result<()> {
+        self.handle.set_speed(read_kbps, write_kbps)
+    }
+
+    /// Gets the read and write speeds supported by the drive with the inserted medium.
+    pub fn speeds(&self) -> Result<DriveSpeeds> {
+        mmc::speed::speeds(&self.handle)
+    }
+
+    /// Creates a controller to play audio CDs through the drive's audio outputs.
+    ///
+    /// See [`Playback`] for an example.
+    pub fn playback(&self) -> Playback<'_> {
+        Playback::new(self)
+    }
+
+    /// Creates a reader over the data stored in the inserted medium.
+    ///
+    /// The size and block length are taken from [`capacity`][Self::capacity].
+    /// See [`Sectors`] for an example.
+    ///
+    /// # Platform specific behavior
+    ///
+    /// **Linux:** Data is read with `pread`, so this also works with devices that
+    /// don't accept SCSI commands. Blocks that fail are read again with a SCSI READ
+    /// command to get the sense data that explains the error.
+    pub fn sectors(&self) -> Result<Sectors<'_>> {
+        self.sectors_with_capacity(self.capacity()?)
+    }
+
+    /// Creates a reader over the data stored in the inserted medium, with the given
+    /// number of blocks and block length.
+    ///
+    /// This is useful when [`capacity`][Self::capacity] reports the wrong block length,
+    /// which happens with some drives and media.
+    pub fn sectors_with_capacity(&self, capacity: Capacity) -> Result<Sectors<'_>> {
+        if capacity.block_len == 0 {
+            return Err(Error::new(
+                ErrorKind::UnsupportedOperation,
+                "the block length can't be 0",
+            ));
+        }
+        Ok(Sectors::new(self, capacity))
+    }
+
+    /// Reads `count` sectors of a CD starting at `lba` with the READ CD command,
+    /// which can return the parts of the sectors that are normally hidden.
+    ///
+    /// Drivers often limit how much data can be read at once, so `count` should be kept
+    /// small, e.g. 64 KiB worth of sectors.
+    ///
+    /// Some drives ignore the request for C2 error pointers or subchannel data and only
+    /// return the sectors. This fails with [`ErrorKind::UnsupportedOperation`] for them.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use eject::device::{C2ErrorInfo, Device, ReadCdOptions};
+    ///
+    /// let cdrom = Device::open("/dev/cdrom")?;
+    /// let options = ReadCdOptions {
+    ///     c2_errors: C2ErrorInfo::Pointers,
+    ///     ..ReadCdOptions::raw()
+    /// };
+    /// for sector in cdrom.read_cd(0, 16, options)? {
+    ///     if sector.has_c2_errors() {
+    ///         println!("Sector {} is damaged", sector.lba);
+    ///     }
+    /// }
+    /// # eject::error::Result::Ok(())
+    /// ```
+    pub fn read_cd(&self, lba: u32, count: u32, options: ReadCdOptions) -> Result<Vec<CdSector>> {
+        mmc::read_cd::read_cd(&self.handle, lba, count, &options)
+    }
+
+    /// Reads whole CD-DA sectors of 2352 bytes into `buf`, starting at `lba`.
+    pub(crate) fn read_cdda(&self, lba: u32, buf: &mut [u8]) -> Result<()> {
+        mmc::read_cd::read_cdda(&self.handle, lba, buf)
+    }
+
+    /// Sends a raw SCSI command to this drive.
+    ///
+    /// An error is returned only if the command couldn't be delivered. If the
+    /// device rejects it, the returned [`ScsiResponse`] will contain its status and
+    /// sense data, and calling [`ScsiResponse::check`] on it will return an error.
+    ///
+    /// See the [`scsi`][crate::scsi] module docs for an example.
+    ///
+    /// # Platform specific behavior
+    ///
+    /// **Linux:** This uses the `SG_IO` ioctl, which works with `sr`, `sd` and `sg` devices.
+    ///
+    /// **Windows:** This uses `IOCTL_SCSI_PASS_THROUGH_DIRECT`. The timeout is rounded
+    /// up to whole seconds.
+    pub fn execute_scsi(&self, command: &mut ScsiCommand) -> Result<ScsiResponse> {
+        self.handle.execute_scsi(command)
+    }
 }
 
 /// A struct created when a drive's ejection is disabled
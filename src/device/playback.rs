@@ -0,0 +1,150 @@
+use super::{Device, Msf};
+use crate::error::Result;
+
+/// Controls the playback of audio CDs through the drive's audio outputs,
+/// created with [`Device::playback`].
+///
+/// The drive plays the audio by itself, so playback continues after this is dropped.
+///
+/// # Example
+///
+/// ```no_run
+/// use eject::device::{Device, Volume};
+///
+/// let cdrom = Device::open("/dev/cdrom")?;
+/// let playback = cdrom.playback();
+/// playback.set_volume(Volume::all(128))?;
+/// // Play the first three tracks
+/// playback.play_tracks(1, 3)?;
+/// let status = playback.status()?;
+/// println!("Playing track {} at {}", status.track, status.relative);
+/// # eject::error::Result::Ok(())
+/// ```
+///
+/// # Platform specific behavior
+///
+/// **Linux:** This uses the `CDROMPLAYMSF`, `CDROMPLAYTRKIND`, `CDROMPAUSE`,
+/// `CDROMRESUME`, `CDROMSTOP`, `CDROMVOLCTRL`, `CDROMVOLREAD` and `CDROMSUBCHNL`
+/// ioctls, and MMC commands if they fail.
+pub struct Playback<'a> {
+    device: &'a Device,
+}
+
+impl<'a> Playback<'a> {
+    pub(crate) fn new(device: &'a Device) -> Self {
+        Self { device }
+    }
+
+    /// Plays from the start of track `first` to the end of track `last`.
+    pub fn play_tracks(&self, first: u8, last: u8) -> Result<()> {
+        self.device.handle.play_tracks(first, last)
+    }
+
+    /// Plays from `start` until right before `end`.
+    pub fn play_msf(&self, start: Msf, end: Msf) -> Result<()> {
+        self.device.handle.play_msf(start, end)
+    }
+
+    /// Pauses playback, keeping the current position.
+    pub fn pause(&self) -> Result<()> {
+        self.device.handle.pause_audio(true)
+    }
+
+    /// Resumes playback after a [`pause`][Self::pause].
+    pub fn resume(&self) -> Result<()> {
+        self.device.handle.pause_audio(false)
+    }
+
+    /// Stops playback.
+    pub fn stop(&self) -> Result<()> {
+        self.device.handle.stop_audio()
+    }
+
+    /// Gets the volume of each output channel.
+    pub fn volume(&self) -> Result<Volume> {
+        self.device.handle.volume()
+    }
+
+    /// Sets the volume of each output channel.
+    pub fn set_volume(&self, volume: Volume) -> Result<()> {
+        self.device.handle.set_volume(volume)
+    }
+
+    /// Gets the state of playback and the current position.
+    pub fn status(&self) -> Result<PlaybackStatus> {
+        self.device.handle.playback_status()
+    }
+}
+
+/// Volume of the audio outputs of a drive, used by [`Playback`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Volume {
+    /// Volume of each output channel, from 0 (muted) to 255. Usually only the first
+    /// two are used, for the left and right channels.
+    pub channels: [u8; 4],
+}
+
+impl Volume {
+    /// Creates a volume with the same level on every channel.
+    pub fn all(level: u8) -> Self {
+        Self {
+            channels: [level; 4],
+        }
+    }
+
+    /// Creates a volume with different levels on the left and right channels.
+    pub fn stereo(left: u8, right: u8) -> Self {
+        Self {
+            channels: [left, right, 0, 0],
+        }
+    }
+}
+
+/// Current state of audio playback, returned by [`Playback::status`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct PlaybackStatus {
+    /// Whether audio is being played.
+    pub state: AudioState,
+    /// Number of the current track.
+    pub track: u8,
+    /// Number of the current index inside the track.
+    pub index: u8,
+    /// Current position on the disc.
+    pub absolute: Msf,
+    /// Current position from the start of the track.
+    pub relative: Msf,
+}
+
+/// State of audio playback, in a [`PlaybackStatus`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AudioState {
+    /// The drive doesn't report the audio state.
+    Unsupported,
+    /// Audio is being played.
+    Playing,
+    /// Playback is paused.
+    Paused,
+    /// Playback finished successfully.
+    Completed,
+    /// Playback stopped due to an error.
+    Error,
+    /// There's no current audio status to return, e.g. nothing has been played.
+    NoStatus,
+    /// Any other state, with its MMC code.
+    Other(u8),
+}
+
+impl From<u8> for AudioState {
+    fn from(code: u8) -> Self {
+        match code {
+            0x00 => Self::Unsupported,
+            0x11 => Self::Playing,
+            0x12 => Self::Paused,
+            0x13 => Self::Completed,
+            0x14 => Self::Error,
+            0x15 => Self::NoStatus,
+            code => Self::Other(code),
+        }
+    }
+}
@@ -0,0 +1,282 @@
+/// Type of medium, as defined by the MMC profiles.
+///
+/// Returned by [`Device::media_profile`][super::Device::media_profile].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Profile {
+    /// Non removable disk, e.g. a hard drive.
+    NonRemovableDisk,
+    /// Removable disk, e.g. a USB stick or memory card.
+    RemovableDisk,
+    /// Magneto-optical erasable disc.
+    MoErasable,
+    /// Magneto-optical write once disc.
+    MoWriteOnce,
+    /// Advance storage magneto-optical disc.
+    AsMo,
+    /// CD-ROM, including pressed audio CDs.
+    CdRom,
+    /// CD-R.
+    CdR,
+    /// CD-RW.
+    CdRw,
+    /// DVD-ROM.
+    DvdRom,
+    /// DVD-R with sequential recording.
+    DvdRSequential,
+    /// DVD-RAM.
+    DvdRam,
+    /// DVD-RW with restricted overwrite.
+    DvdRwRestrictedOverwrite,
+    /// DVD-RW with sequential recording.
+    DvdRwSequential,
+    /// Dual layer DVD-R with sequential recording.
+    DvdRDualLayerSequential,
+    /// Dual layer DVD-R with layer jump recording.
+    DvdRDualLayerJump,
+    /// Dual layer DVD-RW.
+    DvdRwDualLayer,
+    /// DVD-Download disc.
+    DvdDownload,
+    /// DVD+RW.
+    DvdPlusRw,
+    /// DVD+R.
+    DvdPlusR,
+    /// Dual layer DVD+RW.
+    DvdPlusRwDualLayer,
+    /// Dual layer DVD+R.
+    DvdPlusRDualLayer,
+    /// BD-ROM, including pressed Blu-ray movies.
+    BdRom,
+    /// BD-R with sequential recording.
+    BdRSequential,
+    /// BD-R with random recording.
+    BdRRandom,
+    /// BD-RE.
+    BdRe,
+    /// HD DVD-ROM.
+    HdDvdRom,
+    /// HD DVD-R.
+    HdDvdR,
+    /// HD DVD-RAM.
+    HdDvdRam,
+    /// HD DVD-RW.
+    HdDvdRw,
+    /// Dual layer HD DVD-R.
+    HdDvdRDualLayer,
+    /// Dual layer HD DVD-RW.
+    HdDvdRwDualLayer,
+    /// The drive doesn't conform to any profile.
+    NonConforming,
+    /// Any other profile, with its MMC code.
+    Other(u16),
+}
+
+impl Profile {
+    /// Returns the MMC code of this profile.
+    pub fn code(&self) -> u16 {
+        match self {
+            Self::NonRemovableDisk => 0x0001,
+            Self::RemovableDisk => 0x0002,
+            Self::MoErasable => 0x0003,
+            Self::MoWriteOnce => 0x0004,
+            Self::AsMo => 0x0005,
+            Self::CdRom => 0x0008,
+            Self::CdR => 0x0009,
+            Self::CdRw => 0x000a,
+            Self::DvdRom => 0x0010,
+            Self::DvdRSequential => 0x0011,
+            Self::DvdRam => 0x0012,
+            Self::DvdRwRestrictedOverwrite => 0x0013,
+            Self::DvdRwSequential => 0x0014,
+            Self::DvdRDualLayerSequential => 0x0015,
+            Self::DvdRDualLayerJump => 0x0016,
+            Self::DvdRwDualLayer => 0x0017,
+            Self::DvdDownload => 0x0018,
+            Self::DvdPlusRw => 0x001a,
+            Self::DvdPlusR => 0x001b,
+            Self::DvdPlusRwDualLayer => 0x002a,
+            Self::DvdPlusRDualLayer => 0x002b,
+            Self::BdRom => 0x0040,
+            Self::BdRSequential => 0x0041,
+            Self::BdRRandom => 0x0042,
+            Self::BdRe => 0x0043,
+            Self::HdDvdRom => 0x0050,
+            Self::HdDvdR => 0x0051,
+            Self::HdDvdRam => 0x0052,
+            Self::HdDvdRw => 0x0053,
+            Self::HdDvdRDualLayer => 0x0058,
+            Self::HdDvdRwDualLayer => 0x005a,
+            Self::NonConforming => 0xffff,
+            Self::Other(code) => *code,
+        }
+    }
+
+    /// Returns whether this is a CD profile.
+    pub fn is_cd(&self) -> bool {
+        matches!(self, Self::CdRom | Self::CdR | Self::CdRw)
+    }
+
+    /// Returns whether this is a DVD profile, not including HD DVD.
+    pub fn is_dvd(&self) -> bool {
+        matches!(
+            self,
+            Self::DvdRom
+                | Self::DvdRSequential
+                | Self::DvdRam
+                | Self::DvdRwRestrictedOverwrite
+                | Self::DvdRwSequential
+                | Self::DvdRDualLayerSequential
+                | Self::DvdRDualLayerJump
+                | Self::DvdRwDualLayer
+                | Self::DvdDownload
+                | Self::DvdPlusRw
+                | Self::DvdPlusR
+                | Self::DvdPlusRwDualLayer
+                | Self::DvdPlusRDualLayer
+        )
+    }
+
+    /// Returns whether this is a Blu-ray profile.
+    pub fn is_bd(&self) -> bool {
+        matches!(
+            self,
+            Self::BdRom | Self::BdRSequential | Self::BdRRandom | Self::BdRe
+        )
+    }
+}
+
+impl From<u16> for Profile {
+    fn from(code: u16) -> Self {
+        match code {
+            0x0001 => Self::NonRemovableDisk,
+            0x0002 => Self::RemovableDisk,
+            0x0003 => Self::MoErasable,
+            0x0004 => Self::MoWriteOnce,
+            0x0005 => Self::AsMo,
+            0x0008 => Self::CdRom,
+            0x0009 => Self::CdR,
+            0x000a => Self::CdRw,
+            0x0010 => Self::DvdRom,
+            0x0011 => Self::DvdRSequential,
+            0x0012 => Self::DvdRam,
+            0x0013 => Self::DvdRwRestrictedOverwrite,
+            0x0014 => Self::DvdRwSequential,
+            0x0015 => Self::DvdRDualLayerSequential,
+            0x0016 => Self::DvdRDualLayerJump,
+            0x0017 => Self::DvdRwDualLayer,
+            0x0018 => Self::DvdDownload,
+            0x001a => Self::DvdPlusRw,
+            0x001b => Self::DvdPlusR,
+            0x002a => Self::DvdPlusRwDualLayer,
+            0x002b => Self::DvdPlusRDualLayer,
+            0x0040 => Self::BdRom,
+            0x0041 => Self::BdRSequential,
+            0x0042 => Self::BdRRandom,
+            0x0043 => Self::BdRe,
+            0x0050 => Self::HdDvdRom,
+            0x0051 => Self::HdDvdR,
+            0x0052 => Self::HdDvdRam,
+            0x0053 => Self::HdDvdRw,
+            0x0058 => Self::HdDvdRDualLayer,
+            0x005a => Self::HdDvdRwDualLayer,
+            0xffff => Self::NonConforming,
+            code => Self::Other(code),
+        }
+    }
+}
+
+/// Profiles and features supported by a drive, returned by
+/// [`Device::features`][super::Device::features].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct DriveFeatures {
+    /// Profile of the inserted medium, or `None` if there's no medium.
+    pub current_profile: Option<Profile>,
+    /// All profiles supported by the drive.
+    pub profiles: Vec<Profile>,
+    /// All features supported by the drive.
+    pub features: Vec<Feature>,
+}
+
+impl DriveFeatures {
+    /// Returns the feature with this code, if the drive supports it.
+    pub fn get(&self, code: u16) -> Option<&Feature> {
+        self.features.iter().find(|feature| feature.code == code)
+    }
+}
+
+/// A feature descriptor returned by the GET CONFIGURATION command.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Feature {
+    /// MMC code of the feature.
+    pub code: u16,
+    /// Version of the feature descriptor.
+    pub version: u8,
+    /// Whether the feature is always current.
+    pub persistent: bool,
+    /// Whether the feature can be used with the inserted medium.
+    pub current: bool,
+    /// Feature dependent data.
+    pub data: Vec<u8>,
+}
+
+impl Feature {
+    /// Returns the name given to this feature by the MMC standard,
+    /// or `None` if it's not known.
+    pub fn name(&self) -> Option<&'static str> {
+        Some(match self.code {
+            0x0000 => "Profile List",
+            0x0001 => "Core",
+            0x0002 => "Morphing",
+            0x0003 => "Removable Medium",
+            0x0004 => "Write Protect",
+            0x0010 => "Random Readable",
+            0x001d => "Multi-Read",
+            0x001e => "CD Read",
+            0x001f => "DVD Read",
+            0x0020 => "Random Writable",
+            0x0021 => "Incremental Streaming Writable",
+            0x0023 => "Formattable",
+            0x0024 => "Hardware Defect Management",
+            0x0025 => "Write Once",
+            0x0026 => "Restricted Overwrite",
+            0x0027 => "CD-RW CAV Write",
+            0x0028 => "MRW",
+            0x0029 => "Enhanced Defect Reporting",
+            0x002a => "DVD+RW",
+            0x002b => "DVD+R",
+            0x002c => "Rigid Restricted Overwrite",
+            0x002d => "CD Track at Once",
+            0x002e => "CD Mastering",
+            0x002f => "DVD-R/-RW Write",
+            0x0033 => "Layer Jump Recording",
+            0x0037 => "CD-RW Media Write Support",
+            0x0038 => "BD-R Pseudo-Overwrite",
+            0x003a => "DVD+RW Dual Layer",
+            0x003b => "DVD+R Dual Layer",
+            0x0040 => "BD Read",
+            0x0041 => "BD Write",
+            0x0042 => "Timely Safe Recording",
+            0x0050 => "HD DVD Read",
+            0x0051 => "HD DVD Write",
+            0x0080 => "Hybrid Disc",
+            0x0100 => "Power Management",
+            0x0101 => "S.M.A.R.T.",
+            0x0102 => "Embedded Changer",
+            0x0103 => "CD Audio External Play",
+            0x0104 => "Microcode Upgrade",
+            0x0105 => "Timeout",
+            0x0106 => "DVD CSS",
+            0x0107 => "Real Time Streaming",
+            0x0108 => "Drive Serial Number",
+            0x0109 => "Media Serial Number",
+            0x010a => "Disc Control Blocks",
+            0x010b => "DVD CPRM",
+            0x010c => "Firmware Information",
+            0x010d => "AACS",
+            0x0110 => "VCPS",
+            _ => return None,
+        })
+    }
+}
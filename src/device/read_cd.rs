@@ -0,0 +1,180 @@
+/// Options for [`Device::read_cd`][super::Device::read_cd], which select the type of
+/// sectors expected and which of their parts will be returned.
+///
+/// The default options read the user data of sectors of any type.
+///
+/// # Example
+///
+/// ```
+/// use eject::device::{ReadCdOptions, SectorType};
+///
+/// // Read the 2324 bytes of user data of Video CD sectors
+/// let options = ReadCdOptions {
+///     sector_type: SectorType::Mode2Form2,
+///     ..Default::default()
+/// };
+/// // Read whole sectors of any type, with their subchannel
+/// let raw = ReadCdOptions::raw();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ReadCdOptions {
+    /// Type of sectors expected. The drive will fail if another type is found.
+    pub sector_type: SectorType,
+    /// Return the 12 byte sync pattern of data sectors.
+    pub sync: bool,
+    /// Which headers of data sectors to return.
+    pub headers: HeaderCodes,
+    /// Return the user data of the sectors. For audio sectors this is the whole sector.
+    pub user_data: bool,
+    /// Return the error detection and correction codes of data sectors.
+    pub edc_ecc: bool,
+    /// Return the C2 error pointers of each sector.
+    pub c2_errors: C2ErrorInfo,
+    /// Which subchannel data to return with each sector.
+    pub subchannel: Subchannel,
+}
+
+impl ReadCdOptions {
+    /// Options to read whole audio sectors.
+    pub fn audio() -> Self {
+        Self {
+            sector_type: SectorType::CdDa,
+            ..Default::default()
+        }
+    }
+
+    /// Options to read whole sectors of any type, with their raw subchannel.
+    pub fn raw() -> Self {
+        Self {
+            sync: true,
+            headers: HeaderCodes::All,
+            edc_ecc: true,
+            subchannel: Subchannel::Raw,
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for ReadCdOptions {
+    fn default() -> Self {
+        Self {
+            sector_type: SectorType::Any,
+            sync: false,
+            headers: HeaderCodes::None,
+            user_data: true,
+            edc_ecc: false,
+            c2_errors: C2ErrorInfo::None,
+            subchannel: Subchannel::None,
+        }
+    }
+}
+
+/// Type of CD sectors, for [`ReadCdOptions::sector_type`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SectorType {
+    /// Any type of sector.
+    Any,
+    /// Audio sectors.
+    CdDa,
+    /// Mode 1 data sectors, e.g. a CD-ROM.
+    Mode1,
+    /// Mode 2 sectors without a form.
+    Mode2Formless,
+    /// CD-ROM XA mode 2 form 1 sectors, with 2048 bytes of data and error correction.
+    Mode2Form1,
+    /// CD-ROM XA mode 2 form 2 sectors, with 2324 bytes of data, e.g. a Video CD.
+    Mode2Form2,
+}
+
+/// Headers of data sectors to return, for [`ReadCdOptions::headers`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum HeaderCodes {
+    /// No headers.
+    None,
+    /// Only the 4 byte header with the address and mode.
+    Header,
+    /// Only the 8 byte subheader of mode 2 form 1 and form 2 sectors.
+    SubHeader,
+    /// Both the header and the subheader.
+    All,
+}
+
+/// C2 error information to return, for [`ReadCdOptions::c2_errors`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum C2ErrorInfo {
+    /// No C2 error information.
+    None,
+    /// 294 bytes with one bit for each byte of the sector, set if it had a C2 error.
+    Pointers,
+    /// The 294 bytes of pointers, followed by a byte that is the OR of all of
+    /// them and a padding byte.
+    PointersAndBlockBits,
+}
+
+impl C2ErrorInfo {
+    /// Returns the number of bytes of C2 error information per sector.
+    pub fn len(&self) -> usize {
+        match self {
+            Self::None => 0,
+            Self::Pointers => 294,
+            Self::PointersAndBlockBits => 296,
+        }
+    }
+
+    /// Returns whether no C2 error information is returned.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Subchannel data to return, for [`ReadCdOptions::subchannel`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Subchannel {
+    /// No subchannel data.
+    None,
+    /// 96 bytes with the P to W subchannels interleaved, as stored on the disc.
+    Raw,
+    /// The 16 bytes of the Q subchannel, formatted by the drive.
+    Q,
+    /// 96 bytes with the R to W subchannels, deinterleaved and corrected by the drive.
+    Rw,
+}
+
+impl Subchannel {
+    /// Returns the number of bytes of subchannel data per sector.
+    pub fn len(&self) -> usize {
+        match self {
+            Self::None => 0,
+            Self::Q => 16,
+            Self::Raw | Self::Rw => 96,
+        }
+    }
+
+    /// Returns whether no subchannel data is returned.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A sector returned by [`Device::read_cd`][super::Device::read_cd].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct CdSector {
+    /// Logical block address of this sector.
+    pub lba: u32,
+    /// Sync, headers, user data and EDC/ECC, as selected by the options.
+    pub data: Vec<u8>,
+    /// C2 error information, empty if it wasn't requested.
+    pub c2_errors: Vec<u8>,
+    /// Subchannel data, empty if it wasn't requested.
+    pub subchannel: Vec<u8>,
+}
+
+impl CdSector {
+    /// Returns whether the drive reported a C2 error in any byte of this sector.
+    ///
+    /// Always `false` if C2 error information wasn't requested.
+    pub fn has_c2_errors(&self) -> bool {
+        self.c2_errors.iter().take(294).any(|&byte| byte != 0)
+    }
+}
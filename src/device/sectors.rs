@@ -0,0 +1,183 @@
+use super::{Capacity, Device};
+use crate::error::Error;
+use std::{
+    cmp::min,
+    io::{self, Read, Seek, SeekFrom},
+};
+
+// Max number of bytes read from the device at once
+const CHUNK_LEN: usize = 64 * 1024;
+
+/// A reader over the data stored in the medium, created with [`Device::sectors`].
+///
+/// Data is read from the device in whole blocks, so reads and seeks don't need to be
+/// aligned to the block size. When a block can't be read, the returned
+/// [`io::Error`] will contain a [`ReadError`] with its address.
+///
+/// # Example
+///
+/// ```no_run
+/// use eject::device::Device;
+/// use std::{fs::File, io};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let cdrom = Device::open("/dev/cdrom")?;
+/// // Make an image of the disc
+/// let mut image = File::create("disc.iso")?;
+/// io::copy(&mut cdrom.sectors()?, &mut image)?;
+/// # Ok(())}
+/// ```
+pub struct Sectors<'a> {
+    device: &'a Device,
+    capacity: Capacity,
+    position: u64,
+    buffer: Vec<u8>,
+    buffer_lba: u64,
+}
+
+impl<'a> Sectors<'a> {
+    pub(crate) fn new(device: &'a Device, capacity: Capacity) -> Self {
+        Self {
+            device,
+            capacity,
+            position: 0,
+            buffer: Vec::new(),
+            buffer_lba: 0,
+        }
+    }
+
+    /// Returns the size of each block in bytes.
+    pub fn block_len(&self) -> u32 {
+        self.capacity.block_len
+    }
+
+    /// Returns the total size of the medium in bytes.
+    pub fn len(&self) -> u64 {
+        self.capacity.bytes()
+    }
+
+    /// Returns whether the medium contains no data.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the address of the block that contains the current position.
+    pub fn lba(&self) -> u64 {
+        self.position / self.block_len() as u64
+    }
+
+    /// Fills the buffer with the blocks that start at `lba`.
+    fn fill_buffer(&mut self, lba: u64) -> io::Result<()> {
+        let block_len = self.block_len() as usize;
+        let blocks = min(
+            (CHUNK_LEN / block_len).max(1) as u64,
+            self.capacity.blocks - lba,
+        );
+        self.buffer.resize(blocks as usize * block_len, 0);
+        self.buffer_lba = lba;
+        if self.read_blocks(lba, 0..self.buffer.len()).is_ok() {
+            return Ok(());
+        }
+        // Keep the blocks before the one that failed, so they can still be read
+        for (i, start) in (0..self.buffer.len()).step_by(block_len).enumerate() {
+            if let Err(error) = self.read_blocks(lba + i as u64, start..start + block_len) {
+                if i == 0 {
+                    return Err(ReadError { lba, error }.into());
+                }
+                self.buffer.truncate(start);
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    fn read_blocks(&mut self, lba: u64, range: std::ops::Range<usize>) -> Result<(), Error> {
+        self.device
+            .handle
+            .read_blocks(lba, self.capacity.block_len, &mut self.buffer[range])
+    }
+}
+
+impl Read for Sectors<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.position >= self.len() || buf.is_empty() {
+            return Ok(0);
+        }
+        let block_len = self.block_len() as u64;
+        let buffer_start = self.buffer_lba * block_len;
+        let buffer_end = buffer_start + self.buffer.len() as u64;
+        if !(buffer_start..buffer_end).contains(&self.position) {
+            self.fill_buffer(self.lba())?;
+        }
+        let offset = (self.position - self.buffer_lba * block_len) as usize;
+        let available = &self.buffer[offset..];
+        let len = min(available.len(), buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        self.position += len as u64;
+        Ok(len)
+    }
+}
+
+impl Seek for Sectors<'_> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => self.len().checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+        };
+        self.position = position.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+        Ok(self.position)
+    }
+}
+
+/// Error returned inside an [`io::Error`] by [`Sectors`] when a block can't be read.
+///
+/// # Example
+///
+/// ```no_run
+/// use eject::device::{Device, ReadError};
+/// use std::io::Read;
+///
+/// let cdrom = Device::open("/dev/cdrom")?;
+/// let mut data = Vec::new();
+/// if let Err(error) = cdrom.sectors()?.read_to_end(&mut data) {
+///     if let Some(error) = error.get_ref().and_then(|e| e.downcast_ref::<ReadError>()) {
+///         println!("Block {} is damaged", error.lba());
+///     }
+/// }
+/// # eject::error::Result::Ok(())
+/// ```
+#[derive(thiserror::Error, Debug, Clone)]
+#[error("failed to read block {lba}: {error}")]
+pub struct ReadError {
+    lba: u64,
+    #[source]
+    error: Error,
+}
+
+impl ReadError {
+    /// Returns the address of the block that couldn't be read.
+    pub fn lba(&self) -> u64 {
+        self.lba
+    }
+
+    /// Returns the error returned when reading the block.
+    pub fn error(&self) -> &Error {
+        &self.error
+    }
+}
+
+impl From<ReadError> for io::Error {
+    fn from(e: ReadError) -> Self {
+        let kind = match e.error.os_code() {
+            Some(code) => io::Error::from_raw_os_error(code).kind(),
+            None => e.error.kind().into(),
+        };
+        Self::new(kind, e)
+    }
+}
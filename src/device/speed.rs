@@ -0,0 +1,13 @@
+/// Speeds supported by a drive, returned by [`Device::speeds`][super::Device::speeds].
+///
+/// Speeds are in kB/s, where 1x is 176 kB/s for CDs, 1385 kB/s for DVDs
+/// and 4496 kB/s for Blu-ray discs.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub struct DriveSpeeds {
+    /// Read speeds, from fastest to slowest.
+    pub read: Vec<u32>,
+    /// Write speeds for the inserted medium, from fastest to slowest.
+    /// Empty if the drive can't write to it.
+    pub write: Vec<u32>,
+}
@@ -1,3 +1,12 @@
+use crate::error::{Error, ErrorKind, Result};
+use std::{
+    thread::sleep,
+    time::{Duration, Instant},
+};
+
+// How often the status is checked while waiting for it to change
+const WAIT_INTERVAL: Duration = Duration::from_millis(250);
+
 /// Position of the drive's tray and whether it has data loaded.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum DriveStatus {
@@ -8,7 +17,8 @@ pub enum DriveStatus {
     /// The drive is not available yet. With CD drives this happens for a few
     /// seconds after the tray is closed.
     ///
-    /// This status is not supported on Windows yet. There it'll be reported as [`Empty`][Self::Empty].
+    /// On Windows this is only reported while the drive says it's busy, or by
+    /// devices that don't support GET EVENT STATUS NOTIFICATION.
     NotReady,
     /// The drive has data loaded. If it reads from removable media
     /// (e.g. CDs/floppy/SD cards) then one is inserted.
@@ -24,3 +34,95 @@ impl DriveStatus {
         }
     }
 }
+
+/// A condition on the status of a drive, used by [`Device::wait_for`][super::Device::wait_for].
+///
+/// It's implemented for [`DriveStatus`], which is met by that status, and for
+/// functions that take a status and return whether the condition is met.
+pub trait StatusCondition {
+    /// Returns whether this status meets the condition.
+    fn is_met(&self, status: DriveStatus) -> bool;
+}
+
+impl StatusCondition for DriveStatus {
+    fn is_met(&self, status: DriveStatus) -> bool {
+        *self == status
+    }
+}
+
+impl<F: Fn(DriveStatus) -> bool> StatusCondition for F {
+    fn is_met(&self, status: DriveStatus) -> bool {
+        self(status)
+    }
+}
+
+/// Gets the status with `status` until it meets `condition` or the timeout passes.
+pub(crate) fn wait_for(
+    mut status: impl FnMut() -> Result<DriveStatus>,
+    condition: impl StatusCondition,
+    timeout: Duration,
+) -> Result<DriveStatus> {
+    let start = Instant::now();
+    loop {
+        let status = status()?;
+        if condition.is_met(status) {
+            return Ok(status);
+        }
+        let remaining = timeout.saturating_sub(start.elapsed());
+        if remaining.is_zero() {
+            return Err(Error {
+                status: Some(status),
+                ..Error::new(
+                    ErrorKind::TimedOut,
+                    format!("timed out waiting for the drive, its status is {status:?}"),
+                )
+            });
+        }
+        sleep(WAIT_INTERVAL.min(remaining));
+    }
+}
+
+/// Whether the drive is ready after closing its tray, with or without a medium.
+pub(crate) fn retracted(status: DriveStatus) -> bool {
+    matches!(status, DriveStatus::Loaded | DriveStatus::Empty)
+}
+
+/// Status of a drive with the details reported by its events,
+/// returned by [`Device::status_details`][super::Device::status_details].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct StatusDetails {
+    /// Position of the tray and whether there's a medium.
+    pub status: DriveStatus,
+    /// Power state of the drive, if it reports it.
+    pub power: Option<PowerState>,
+    /// Whether the drive is busy, e.g. because it's loading or formatting a medium.
+    pub busy: bool,
+}
+
+/// Power state of a drive, in [`StatusDetails`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PowerState {
+    /// The drive is ready to execute commands.
+    Active,
+    /// The drive is ready but some parts may be turned off.
+    Idle,
+    /// The disc has stopped spinning. The drive will start it when needed.
+    Standby,
+    /// The drive only responds to commands that wake it up.
+    Sleep,
+    /// Any other state, with its MMC code.
+    Other(u8),
+}
+
+impl From<u8> for PowerState {
+    fn from(code: u8) -> Self {
+        match code {
+            0x01 => Self::Active,
+            0x02 => Self::Idle,
+            0x03 => Self::Standby,
+            0x04 => Self::Sleep,
+            code => Self::Other(code),
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use std::fmt;
+
+// Lead-out (6750 sectors), lead-in (4500) and pregap (150) between two sessions
+pub(super) const SESSION_GAP: u32 = 11400;
+
+/// Table of contents of a CD, returned by [`Device::toc`][super::Device::toc].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Toc {
+    /// Number of the first track, usually 1.
+    pub first_track: u8,
+    /// Number of the last track.
+    pub last_track: u8,
+    /// All tracks in the disc, in order.
+    pub tracks: Vec<TocTrack>,
+    /// Logical block address where the lead-out area starts, right after the last track.
+    pub lead_out_lba: u32,
+    /// Address where the lead-out area starts, in minutes, seconds and frames.
+    pub lead_out_msf: Msf,
+}
+
+impl Toc {
+    /// Returns the track with this number, if it exists.
+    pub fn track(&self, number: u8) -> Option<&TocTrack> {
+        self.tracks.iter().find(|track| track.number == number)
+    }
+
+    /// Returns the logical block address right after the end of the track
+    /// with this number, if it exists.
+    ///
+    /// The gap between sessions is not included.
+    pub fn track_end(&self, number: u8) -> Option<u32> {
+        let index = self
+            .tracks
+            .iter()
+            .position(|track| track.number == number)?;
+        let track = &self.tracks[index];
+        Some(match self.tracks.get(index + 1) {
+            Some(next) if next.session != track.session => {
+                next.start_lba.saturating_sub(SESSION_GAP)
+            }
+            Some(next) => next.start_lba,
+            None => self.lead_out_lba,
+        })
+    }
+
+    /// Puts the tracks that start at or after `start_lba` in a second session, for
+    /// when the only thing known about sessions is where the last one starts.
+    pub(crate) fn split_last_session(&mut self, start_lba: u32) {
+        for track in &mut self.tracks {
+            track.session = if track.start_lba >= start_lba { 2 } else { 1 };
+        }
+    }
+}
+
+/// A track listed in a [`Toc`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct TocTrack {
+    /// Number of the track, from 1 to 99.
+    pub number: u8,
+    /// Number of the session that contains this track, starting at 1.
+    pub session: u8,
+    /// Logical block address where this track starts.
+    pub start_lba: u32,
+    /// Address where this track starts, in minutes, seconds and frames.
+    pub start_msf: Msf,
+    /// Type of the track's contents.
+    pub control: TrackControl,
+}
+
+/// Control bits of a track, which describe the type of its contents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TrackControl(pub u8);
+
+impl TrackControl {
+    /// Returns whether this is an audio track.
+    pub fn is_audio(&self) -> bool {
+        self.0 & 0x04 == 0
+    }
+
+    /// Returns whether this is a data track.
+    pub fn is_data(&self) -> bool {
+        !self.is_audio()
+    }
+
+    /// Returns whether the audio of this track has pre-emphasis.
+    pub fn pre_emphasis(&self) -> bool {
+        self.is_audio() && self.0 & 0x01 != 0
+    }
+
+    /// Returns whether digital copies of this track are permitted.
+    pub fn copy_permitted(&self) -> bool {
+        self.0 & 0x02 != 0
+    }
+
+    /// Returns whether this is an audio track with four channels instead of two.
+    pub fn four_channel(&self) -> bool {
+        self.is_audio() && self.0 & 0x08 != 0
+    }
+}
+
+/// An address on a CD in minutes, seconds and frames, with 75 frames per second.
+///
+/// MSF addresses are 2 seconds (150 frames) ahead of logical block addresses.
+///
+/// # Example
+///
+/// ```
+/// use eject::device::Msf;
+///
+/// let msf = Msf::from_lba(0);
+/// assert_eq!(msf.to_string(), "00:02:00");
+/// assert_eq!(msf.to_lba(), 0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Msf {
+    /// Minutes.
+    pub minute: u8,
+    /// Seconds, from 0 to 59.
+    pub second: u8,
+    /// Frames, from 0 to 74.
+    pub frame: u8,
+}
+
+impl Msf {
+    /// Converts a logical block address to an MSF address.
+    ///
+    /// Minutes saturate at 255.
+    pub fn from_lba(lba: u32) -> Self {
+        let frames = lba.saturating_add(150);
+        Self {
+            minute: (frames / (60 * 75)).min(u8::MAX.into()) as u8,
+            second: (frames / 75 % 60) as u8,
+            frame: (frames % 75) as u8,
+        }
+    }
+
+    /// Converts this address to a logical block address,
+    /// which will be 0 if this address is in the first 2 seconds.
+    pub fn to_lba(&self) -> u32 {
+        self.frames().saturating_sub(150)
+    }
+
+    /// Returns the total number of frames in this address.
+    pub fn frames(&self) -> u32 {
+        (self.minute as u32 * 60 + self.second as u32) * 75 + self.frame as u32
+    }
+}
+
+impl fmt::Display for Msf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.minute, self.second, self.frame)
+    }
+}
+
+/// Type of the tracks on a CD, returned by [`Device::disc_kind`][super::Device::disc_kind].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DiscKind {
+    /// Only audio tracks, e.g. a music CD.
+    Audio,
+    /// Only data tracks in mode 1, e.g. a CD-ROM.
+    DataMode1,
+    /// Only data tracks in mode 2.
+    DataMode2,
+    /// Only data tracks in CD-ROM XA mode 2 form 1, e.g. a multisession CD.
+    XaMode2Form1,
+    /// Only data tracks in CD-ROM XA mode 2 form 2, e.g. a Video CD.
+    XaMode2Form2,
+    /// Both audio and data tracks, e.g. an enhanced CD.
+    Mixed,
+}
+
+/// Location of the last session of a CD, returned by
+/// [`Device::last_session`][super::Device::last_session].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct LastSession {
+    /// Logical block address where the first track of the last session starts.
+    pub start_lba: u32,
+    /// Whether the disc has more than one session.
+    pub multisession: bool,
+}
@@ -19,9 +19,5 @@ pub fn first_cdrom() -> Result<Device> {
             x => return x,
         }
     }
-    Err(Error {
-        code: 0,
-        message: "No CD drive found".to_owned(),
-        kind: ErrorKind::NotFound,
-    })
+    Err(Error::new(ErrorKind::NotFound, "No CD drive found"))
 }
@@ -22,6 +22,17 @@
 //! assert_eq!(std_err.raw_os_error(), eject_err.raw_os_error());
 //! ```
 //!
+//! And back, without losing any information.
+//! ```
+//! use eject::{device::Device, error::Error};
+//! use std::io;
+//!
+//! let eject_err = Device::open("doesntexist").err().unwrap();
+//! let std_err: io::Error = eject_err.clone().into();
+//!
+//! assert_eq!(Error::from(std_err).kind(), eject_err.kind());
+//! ```
+//!
 //! If the error comes from the OS, you can get its OS specific code.
 //! ```
 //! use eject::device::Device;
@@ -29,6 +40,21 @@
 //! let error = Device::open("doesntexist").err().unwrap();
 //! println!("Error code: {}", error.os_code().unwrap());
 //! ```
+//!
+//! If the error was reported by a SCSI device, you can get its decoded sense data.
+//! ```no_run
+//! use eject::device::Device;
+//!
+//! let cdrom = Device::open("/dev/cdrom")?;
+//! if let Err(error) = cdrom.eject() {
+//!     if let Some(sense) = error.sense() {
+//!         println!("Sense key: {:?}, {}", sense.key(), sense.description());
+//!     }
+//! }
+//! # eject::error::Result::Ok(())
+//! ```
+
+use crate::{device::DriveStatus, scsi::Sense};
 
 /// Result returned by functions in this crate.
 ///
@@ -47,9 +73,24 @@ pub struct Error {
     pub(crate) message: String,
     /// OS agnostic error category.
     pub(crate) kind: ErrorKind,
+    /// Sense data if the error was reported by a SCSI device.
+    pub(crate) sense: Option<Sense>,
+    /// Last status of the drive if the error comes from waiting for it to change.
+    pub(crate) status: Option<DriveStatus>,
 }
 
 impl Error {
+    /// Creates an error that doesn't come from the OS or a SCSI device.
+    pub(crate) fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            code: 0,
+            message: message.into(),
+            kind,
+            sense: None,
+            status: None,
+        }
+    }
+
     /// Returns the OS specific error code or `None` if the
     /// error doesn't come directly from the OS.
     pub fn os_code(&self) -> Option<i32> {
@@ -64,18 +105,58 @@ impl Error {
     pub fn kind(&self) -> ErrorKind {
         self.kind
     }
+
+    /// Returns the sense data sent by the device if this error
+    /// comes from a failed SCSI command.
+    pub fn sense(&self) -> Option<&Sense> {
+        self.sense.as_ref()
+    }
+
+    /// Returns the last status of the drive if this error comes from
+    /// waiting for it to change, e.g. with [`Device::wait_for`][crate::device::Device::wait_for].
+    pub fn status(&self) -> Option<DriveStatus> {
+        self.status
+    }
+}
+
+impl From<Sense> for Error {
+    fn from(sense: Sense) -> Self {
+        let error = Self::new(sense.error_kind(), format!("SCSI command failed: {sense}"));
+        Self {
+            sense: Some(sense),
+            ..error
+        }
+    }
 }
 
 impl From<Error> for std::io::Error {
     fn from(e: Error) -> Self {
         if e.code == 0 {
-            Self::new(e.kind.into(), e.message)
+            Self::new(e.kind.into(), e)
         } else {
             Self::from_raw_os_error(e.code)
         }
     }
 }
 
+impl From<std::io::Error> for Error {
+    /// Gets back the original error if `e` was created from an [`Error`].
+    fn from(e: std::io::Error) -> Self {
+        if let Some(code) = e.raw_os_error() {
+            return Self::from_raw_os_error(code);
+        }
+        let kind = e.kind().into();
+        match e.into_inner().map(|inner| inner.downcast::<Self>()) {
+            Some(Ok(inner)) => *inner,
+            Some(Err(inner)) => Self::new(kind, inner.to_string()),
+            None => Self::new(
+                kind,
+                std::io::Error::from(std::io::ErrorKind::from(kind)).to_string(),
+            ),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
 #[non_exhaustive]
 /// General categories for OS and library errors.
@@ -96,18 +177,55 @@ pub enum ErrorKind {
     /// This can often happen when a device is not of the type you expect,
     /// or you've opened something that is not a device, like a regular file.
     UnsupportedOperation,
+    /// The device is in use, for example because its medium is mounted.
+    Busy,
+    /// The operation requires a medium but the drive is empty.
+    NoMedium,
+    /// The drive is not ready yet. With CD drives this happens for a few
+    /// seconds after the tray is closed.
+    NotReady,
+    /// The medium can't be removed because the ejection is locked.
+    EjectionLocked,
+    /// The device took too long to respond.
+    TimedOut,
+    /// The device failed to read or write data, often due to a damaged medium.
+    Io,
+    /// The operation was cancelled by the caller.
+    Cancelled,
     /// The category of this error could not be determined.
     Unknown,
 }
 
 impl From<ErrorKind> for std::io::ErrorKind {
     fn from(e: ErrorKind) -> Self {
+        let uncategorized = || std::io::Error::from_raw_os_error(498498498).kind();
         match e {
             ErrorKind::AccessDenied => Self::PermissionDenied,
             ErrorKind::NotFound => Self::NotFound,
             ErrorKind::InvalidPath => Self::InvalidInput,
             ErrorKind::UnsupportedOperation => Self::Unsupported,
-            ErrorKind::Unknown => std::io::Error::from_raw_os_error(498498498).kind(),
+            ErrorKind::Busy | ErrorKind::EjectionLocked => Self::ResourceBusy,
+            ErrorKind::TimedOut => Self::TimedOut,
+            ErrorKind::Cancelled => Self::Other,
+            // These are uncategorized in std, so this is what their OS errors map to
+            ErrorKind::NoMedium | ErrorKind::NotReady | ErrorKind::Io | ErrorKind::Unknown => {
+                uncategorized()
+            }
+        }
+    }
+}
+
+impl From<std::io::ErrorKind> for ErrorKind {
+    fn from(e: std::io::ErrorKind) -> Self {
+        use std::io::ErrorKind as StdErrorKind;
+        match e {
+            StdErrorKind::PermissionDenied => Self::AccessDenied,
+            StdErrorKind::NotFound => Self::NotFound,
+            StdErrorKind::InvalidInput => Self::InvalidPath,
+            StdErrorKind::Unsupported => Self::UnsupportedOperation,
+            StdErrorKind::ResourceBusy => Self::Busy,
+            StdErrorKind::TimedOut => Self::TimedOut,
+            _ => Self::Unknown,
         }
     }
 }
@@ -4,6 +4,8 @@
 //!
 //! [`cd_drives`][discovery::cd_drives] allows you to find all CD drives on a system.
 //!
+//! [`scsi`] allows you to send commands that aren't wrapped by this crate.
+//!
 //! # Example
 //!
 //! ```no_run
@@ -22,12 +24,15 @@
 #![deny(unsafe_op_in_unsafe_fn)]
 #![warn(missing_docs)]
 
+pub mod audio;
 pub mod device;
 pub mod discovery;
 pub mod error;
+mod mmc;
 #[cfg_attr(windows, path = "platforms/windows/mod.rs")]
 #[cfg_attr(target_os = "linux", path = "platforms/linux/mod.rs")]
 #[cfg_attr(target_os = "macos", path = "platforms/macos/mod.rs")]
 mod platform;
+pub mod scsi;
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,97 @@
+use super::mode::{mode_select, mode_sense};
+use crate::{
+    device::{Msf, Volume},
+    error::{Error, ErrorKind, Result},
+    platform::device::DeviceHandle,
+    scsi::{
+        opcodes::{PAUSE_RESUME, PLAY_AUDIO_MSF, STOP_PLAY_SCAN},
+        ScsiCommand,
+    },
+};
+
+const PAGE_CD_AUDIO_CONTROL: u8 = 0x0e;
+// Offset in the CD audio control page of the volume of the first output port,
+// the others follow every 2 bytes
+const FIRST_PORT_VOLUME: usize = 9;
+
+pub fn play_msf(handle: &DeviceHandle, start: Msf, end: Msf) -> Result<()> {
+    let command = [
+        PLAY_AUDIO_MSF,
+        0,
+        0,
+        start.minute,
+        start.second,
+        start.frame,
+        end.minute,
+        end.second,
+        end.frame,
+        0,
+    ];
+    execute(handle, &command)
+}
+
+/// Plays from the start of track `first` to the end of track `last`, using the TOC.
+pub fn play_tracks(handle: &DeviceHandle, first: u8, last: u8) -> Result<()> {
+    let toc = super::toc::read_toc(handle)?;
+    let start = toc.track(first).map(|track| track.start_lba);
+    let end = toc.track_end(last);
+    let (Some(start), Some(end)) = (start, end) else {
+        return Err(Error::new(
+            ErrorKind::NotFound,
+            format!("the disc doesn't have tracks {first} to {last}"),
+        ));
+    };
+    play_msf(handle, Msf::from_lba(start), Msf::from_lba(end))
+}
+
+pub fn pause_audio(handle: &DeviceHandle, pause: bool) -> Result<()> {
+    let resume = u8::from(!pause);
+    execute(handle, &[PAUSE_RESUME, 0, 0, 0, 0, 0, 0, 0, resume, 0])
+}
+
+pub fn stop_audio(handle: &DeviceHandle) -> Result<()> {
+    execute(handle, &[STOP_PLAY_SCAN, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+}
+
+pub fn volume(handle: &DeviceHandle) -> Result<Volume> {
+    parse_volume(&mode_sense(handle, PAGE_CD_AUDIO_CONTROL)?)
+}
+
+pub fn set_volume(handle: &DeviceHandle, volume: Volume) -> Result<()> {
+    let mut page = mode_sense(handle, PAGE_CD_AUDIO_CONTROL)?;
+    apply_volume(&mut page, volume)?;
+    mode_select(handle, &page)
+}
+
+/// Parses the volume of each port in the CD audio control mode page.
+pub fn parse_volume(page: &[u8]) -> Result<Volume> {
+    check_page_len(page)?;
+    let mut volume = Volume::default();
+    for (i, channel) in volume.channels.iter_mut().enumerate() {
+        *channel = page[FIRST_PORT_VOLUME + 2 * i];
+    }
+    Ok(volume)
+}
+
+/// Changes the volume of each port in the CD audio control mode page.
+pub fn apply_volume(page: &mut [u8], volume: Volume) -> Result<()> {
+    check_page_len(page)?;
+    for (i, channel) in volume.channels.into_iter().enumerate() {
+        page[FIRST_PORT_VOLUME + 2 * i] = channel;
+    }
+    Ok(())
+}
+
+fn check_page_len(page: &[u8]) -> Result<()> {
+    if page.len() <= FIRST_PORT_VOLUME + 6 {
+        return Err(Error::new(
+            ErrorKind::Unknown,
+            format!("CD audio control page is too short: {} bytes", page.len()),
+        ));
+    }
+    Ok(())
+}
+
+fn execute(handle: &DeviceHandle, command: &[u8]) -> Result<()> {
+    handle.execute_scsi(&mut ScsiCommand::new(command))?.check()
+}
@@ -0,0 +1,68 @@
+use super::mode::mode_sense;
+use crate::{
+    device::{AudioCapabilities, LoadingMechanism, Mechanism, MediaSupport},
+    error::{Error, ErrorKind, Result},
+    platform::device::DeviceHandle,
+};
+
+const PAGE_CAPABILITIES: u8 = 0x2a;
+// Length of the page in MMC-1, newer versions add more fields after it
+const MIN_PAGE_LEN: usize = 20;
+
+pub fn mechanism(handle: &DeviceHandle) -> Result<Mechanism> {
+    parse_capabilities(&mode_sense(handle, PAGE_CAPABILITIES)?)
+}
+
+/// Parses the CD/DVD capabilities and mechanical status mode page.
+pub fn parse_capabilities(page: &[u8]) -> Result<Mechanism> {
+    if page.len() < MIN_PAGE_LEN {
+        return Err(Error::new(
+            ErrorKind::Unknown,
+            format!("capabilities mode page is too short: {} bytes", page.len()),
+        ));
+    }
+    let bit = |byte: usize, bit: u8| page[byte] & (1 << bit) != 0;
+    let speed = |byte: usize| {
+        Some(u16::from_be_bytes([page[byte], page[byte + 1]]).into()).filter(|&speed| speed != 0)
+    };
+    let loading = LoadingMechanism::from(page[6] >> 5);
+    Ok(Mechanism {
+        loading,
+        can_eject: bit(6, 3),
+        // Not reported by the drive, changers usually have a motorized tray too
+        can_retract: matches!(
+            loading,
+            LoadingMechanism::Tray | LoadingMechanism::Changer | LoadingMechanism::Cartridge
+        ),
+        can_lock: bit(6, 0),
+        locked: bit(6, 1),
+        prevent_jumper: bit(6, 2),
+        read: MediaSupport {
+            cd_r: bit(2, 0),
+            cd_rw: bit(2, 1),
+            dvd_rom: bit(2, 3),
+            dvd_r: bit(2, 4),
+            dvd_ram: bit(2, 5),
+        },
+        write: MediaSupport {
+            cd_r: bit(3, 0),
+            cd_rw: bit(3, 1),
+            dvd_rom: false,
+            dvd_r: bit(3, 4),
+            dvd_ram: bit(3, 5),
+        },
+        audio: AudioCapabilities {
+            play: bit(4, 0),
+            read_cdda: bit(5, 0),
+            accurate_stream: bit(5, 1),
+            c2_pointers: bit(5, 4),
+            isrc: bit(5, 5),
+            mcn: bit(5, 6),
+            separate_volume: bit(7, 0),
+            volume_levels: Some(u16::from_be_bytes([page[10], page[11]]))
+                .filter(|&levels| levels != 0),
+        },
+        max_read_speed: speed(8),
+        max_write_speed: speed(18),
+    })
+}
@@ -0,0 +1,50 @@
+use crate::{
+    device::Capacity,
+    error::{Error, ErrorKind, Result},
+    platform::device::DeviceHandle,
+    scsi::opcodes::{READ_CAPACITY_10, SERVICE_ACTION_IN_16},
+};
+
+const SA_READ_CAPACITY_16: u8 = 0x10;
+
+pub fn capacity(handle: &DeviceHandle) -> Result<Capacity> {
+    let data = super::read(handle, &[READ_CAPACITY_10, 0, 0, 0, 0, 0, 0, 0, 0, 0], 8)?;
+    let capacity = parse_capacity_10(&data)?;
+    if capacity.blocks <= u32::MAX.into() {
+        return Ok(capacity);
+    }
+    // The last block doesn't fit in 32 bits
+    let mut command = [0u8; 16];
+    command[0] = SERVICE_ACTION_IN_16;
+    command[1] = SA_READ_CAPACITY_16;
+    command[13] = 32;
+    parse_capacity_16(&super::read(handle, &command, 32)?)
+}
+
+pub fn parse_capacity_10(data: &[u8]) -> Result<Capacity> {
+    if data.len() < 8 {
+        return Err(Error::new(
+            ErrorKind::Unknown,
+            format!("READ CAPACITY data is too short: {} bytes", data.len()),
+        ));
+    }
+    let last_lba = u32::from_be_bytes(data[..4].try_into().unwrap());
+    Ok(Capacity {
+        blocks: last_lba as u64 + 1,
+        block_len: u32::from_be_bytes(data[4..8].try_into().unwrap()),
+    })
+}
+
+pub fn parse_capacity_16(data: &[u8]) -> Result<Capacity> {
+    if data.len() < 12 {
+        return Err(Error::new(
+            ErrorKind::Unknown,
+            format!("READ CAPACITY data is too short: {} bytes", data.len()),
+        ));
+    }
+    let last_lba = u64::from_be_bytes(data[..8].try_into().unwrap());
+    Ok(Capacity {
+        blocks: last_lba + 1,
+        block_len: u32::from_be_bytes(data[8..12].try_into().unwrap()),
+    })
+}
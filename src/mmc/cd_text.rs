@@ -0,0 +1,198 @@
+use super::toc::read_toc_format;
+use crate::{
+    device::{CdText, CdTextBlock},
+    error::{ErrorKind, Result},
+    platform::device::DeviceHandle,
+};
+
+const FORMAT_CD_TEXT: u8 = 5;
+const PACK_LEN: usize = 18;
+const PACK_TEXT_LEN: usize = 12;
+const PACK_TITLE: u8 = 0x80;
+const PACK_PERFORMER: u8 = 0x81;
+const PACK_SONGWRITER: u8 = 0x82;
+const PACK_COMPOSER: u8 = 0x83;
+const PACK_ARRANGER: u8 = 0x84;
+const PACK_MESSAGE: u8 = 0x85;
+const PACK_DISC_ID: u8 = 0x86;
+const PACK_CODE: u8 = 0x8e;
+const PACK_SIZE_INFO: u8 = 0x8f;
+// Offset in the size info of the language code of each block
+const SIZE_INFO_LANGUAGES: usize = 28;
+// Character code of text with two bytes per character
+const CHARACTER_CODE_MS_JIS: u8 = 0x80;
+
+pub fn read_cd_text(handle: &DeviceHandle) -> Result<CdText> {
+    // Get the length first, some drives fail if asked for more than they have
+    let header = match read_toc_format(handle, FORMAT_CD_TEXT, 0, 4) {
+        Ok(header) => header,
+        // Many drives reject the format when the disc has no CD-TEXT
+        Err(err) if err.kind == ErrorKind::UnsupportedOperation => return Ok(CdText::default()),
+        Err(err) => return Err(err),
+    };
+    let len = u16::from_be_bytes([header[0], header[1]]).saturating_add(2);
+    if len <= 4 {
+        return Ok(CdText::default());
+    }
+    let data = read_toc_format(handle, FORMAT_CD_TEXT, 0, len)?;
+    Ok(parse_cd_text(&data))
+}
+
+/// Parses the response to READ TOC format 5.
+pub fn parse_cd_text(data: &[u8]) -> CdText {
+    let packs: Vec<&[u8]> = data
+        .get(4..)
+        .unwrap_or_default()
+        .chunks_exact(PACK_LEN)
+        .collect();
+    // Some drives don't return the CRCs
+    let has_crc = packs.iter().any(|pack| pack[16..] != [0, 0]);
+    let (packs, invalid): (Vec<&[u8]>, Vec<&[u8]>) = packs.into_iter().partition(|pack| {
+        !has_crc || crc16(&pack[..16]) == u16::from_be_bytes([pack[16], pack[17]])
+    });
+    let mut block_numbers: Vec<u8> = packs.iter().map(|pack| block_number(pack)).collect();
+    block_numbers.sort_unstable();
+    block_numbers.dedup();
+    let blocks = block_numbers
+        .into_iter()
+        .map(|number| {
+            let block_packs: Vec<&[u8]> = packs
+                .iter()
+                .copied()
+                .filter(|pack| block_number(pack) == number)
+                .collect();
+            parse_block(number, &block_packs)
+        })
+        .collect();
+    CdText {
+        blocks,
+        invalid_packs: invalid.len(),
+    }
+}
+
+fn block_number(pack: &[u8]) -> u8 {
+    (pack[3] >> 4) & 0x07
+}
+
+fn parse_block(number: u8, packs: &[&[u8]]) -> CdTextBlock {
+    let size_info = concat_packs(packs, PACK_SIZE_INFO)
+        .map(|(_, text)| text)
+        .unwrap_or_default();
+    let mut block = CdTextBlock {
+        number,
+        character_code: size_info.first().copied().unwrap_or(0),
+        language: size_info
+            .get(SIZE_INFO_LANGUAGES + number as usize)
+            .copied()
+            .unwrap_or(0),
+        ..Default::default()
+    };
+    let double_byte = block.character_code == CHARACTER_CODE_MS_JIS
+        || packs.iter().any(|pack| pack[3] & 0x80 != 0);
+    for pack_type in [
+        PACK_TITLE,
+        PACK_PERFORMER,
+        PACK_SONGWRITER,
+        PACK_COMPOSER,
+        PACK_ARRANGER,
+        PACK_MESSAGE,
+        PACK_DISC_ID,
+        PACK_CODE,
+    ] {
+        let Some((first_track, text)) = concat_packs(packs, pack_type) else {
+            continue;
+        };
+        let strings = split_strings(&text, double_byte);
+        let mut previous: Option<String> = None;
+        for (track, string) in (first_track..=99).zip(strings) {
+            let string = if string == b"\t" || string == b"\t\t" {
+                // A tab means the same text as the previous track
+                previous.clone()
+            } else {
+                Some(decode(string, block.character_code))
+            };
+            previous.clone_from(&string);
+            let Some(string) = string.filter(|string| !string.is_empty()) else {
+                continue;
+            };
+            if pack_type == PACK_DISC_ID {
+                block.disc_id = Some(string);
+                break;
+            }
+            let entry = if track == 0 {
+                &mut block.album
+            } else {
+                block.tracks.entry(track).or_default()
+            };
+            let field = match pack_type {
+                PACK_TITLE => &mut entry.title,
+                PACK_PERFORMER => &mut entry.performer,
+                PACK_SONGWRITER => &mut entry.songwriter,
+                PACK_COMPOSER => &mut entry.composer,
+                PACK_ARRANGER => &mut entry.arranger,
+                PACK_MESSAGE => &mut entry.message,
+                _ => &mut entry.code,
+            };
+            *field = Some(string);
+        }
+    }
+    block
+}
+
+/// Joins the text of all the packs of a type, in order, returning it together
+/// with the track number of the first one.
+fn concat_packs(packs: &[&[u8]], pack_type: u8) -> Option<(u8, Vec<u8>)> {
+    let mut packs: Vec<&[u8]> = packs
+        .iter()
+        .copied()
+        .filter(|pack| pack[0] == pack_type)
+        .collect();
+    packs.sort_by_key(|pack| pack[2]);
+    let first_track = packs.first()?[1] & 0x7f;
+    let text = packs
+        .iter()
+        .flat_map(|pack| &pack[4..4 + PACK_TEXT_LEN])
+        .copied()
+        .collect();
+    Some((first_track, text))
+}
+
+/// Splits null terminated strings, ignoring the padding after the last one.
+fn split_strings(text: &[u8], double_byte: bool) -> Vec<&[u8]> {
+    let char_len = if double_byte { 2 } else { 1 };
+    let mut strings = Vec::new();
+    let mut start = 0;
+    let mut i = 0;
+    while i + char_len <= text.len() {
+        if text[i..i + char_len].iter().all(|&byte| byte == 0) {
+            strings.push(&text[start..i]);
+            start = i + char_len;
+        }
+        i += char_len;
+    }
+    strings
+}
+
+fn decode(text: &[u8], character_code: u8) -> String {
+    match character_code {
+        // ISO 8859-1 and ASCII map directly to Unicode
+        0x00 | 0x01 => text.iter().map(|&byte| char::from(byte)).collect(),
+        _ => String::from_utf8_lossy(text).into_owned(),
+    }
+}
+
+/// Calculates the CRC of a CD-TEXT pack, which is stored inverted.
+pub fn crc16(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for &byte in data {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    !crc
+}
@@ -0,0 +1,98 @@
+use crate::{
+    device::{DriveFeatures, Feature, Profile},
+    error::{Error, ErrorKind, Result},
+    platform::device::DeviceHandle,
+    scsi::opcodes::GET_CONFIGURATION,
+};
+
+const HEADER_LEN: usize = 8;
+const PROFILE_LIST: u16 = 0x0000;
+// Request type: all features
+const RT_ALL: u8 = 0;
+// Request type: only the header and the requested feature
+const RT_ONE: u8 = 2;
+
+pub fn media_profile(handle: &DeviceHandle) -> Result<Option<Profile>> {
+    let data = get_configuration(handle, RT_ONE, PROFILE_LIST, HEADER_LEN as u16)?;
+    Ok(parse_header(&data)?.0)
+}
+
+pub fn features(handle: &DeviceHandle) -> Result<DriveFeatures> {
+    let data = get_configuration(handle, RT_ALL, 0, u16::MAX - 7)?;
+    parse_features(&data)
+}
+
+fn get_configuration(
+    handle: &DeviceHandle,
+    request_type: u8,
+    starting_feature: u16,
+    len: u16,
+) -> Result<Vec<u8>> {
+    let [feature_hi, feature_lo] = starting_feature.to_be_bytes();
+    let [len_hi, len_lo] = len.to_be_bytes();
+    let command = [
+        GET_CONFIGURATION,
+        request_type,
+        feature_hi,
+        feature_lo,
+        0,
+        0,
+        0,
+        len_hi,
+        len_lo,
+        0,
+    ];
+    super::read(handle, &command, len.into())
+}
+
+/// Returns the current profile and the feature descriptors that follow the header.
+fn parse_header(data: &[u8]) -> Result<(Option<Profile>, &[u8])> {
+    if data.len() < HEADER_LEN {
+        return Err(Error::new(
+            ErrorKind::Unknown,
+            format!("GET CONFIGURATION data is too short: {} bytes", data.len()),
+        ));
+    }
+    // The data length doesn't include its own 4 bytes
+    let data_len = u32::from_be_bytes([data[0], data[1], data[2], data[3]]) as usize + 4;
+    let current = match u16::from_be_bytes([data[6], data[7]]) {
+        0 => None,
+        code => Some(Profile::from(code)),
+    };
+    Ok((current, &data[HEADER_LEN..data.len().min(data_len)]))
+}
+
+pub fn parse_features(data: &[u8]) -> Result<DriveFeatures> {
+    let (current_profile, mut descriptors) = parse_header(data)?;
+    let mut features = Vec::new();
+    while descriptors.len() >= 4 {
+        let len = descriptors[3] as usize;
+        let Some(feature_data) = descriptors.get(4..4 + len) else {
+            break;
+        };
+        features.push(Feature {
+            code: u16::from_be_bytes([descriptors[0], descriptors[1]]),
+            version: (descriptors[2] >> 2) & 0x0f,
+            persistent: descriptors[2] & 0x02 != 0,
+            current: descriptors[2] & 0x01 != 0,
+            data: feature_data.to_vec(),
+        });
+        descriptors = &descriptors[4 + len..];
+    }
+    let profiles = features
+        .iter()
+        .find(|feature| feature.code == PROFILE_LIST)
+        .map(|feature| {
+            feature
+                .data
+                .chunks_exact(4)
+                .map(|profile| Profile::from(u16::from_be_bytes([profile[0], profile[1]])))
+                .collect()
+        })
+        .unwrap_or_default();
+    Ok(DriveFeatures {
+        current_profile,
+        profiles,
+        features,
+    })
+}
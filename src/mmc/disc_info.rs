@@ -0,0 +1,63 @@
+use crate::{
+    device::{DiscInfo, DiscStatus, Msf, SessionState},
+    error::{Error, ErrorKind, Result},
+    platform::device::DeviceHandle,
+    scsi::opcodes::READ_DISC_INFORMATION,
+};
+
+const LEN: u16 = 34;
+
+pub fn disc_info(handle: &DeviceHandle) -> Result<DiscInfo> {
+    let [len_hi, len_lo] = LEN.to_be_bytes();
+    let command = [READ_DISC_INFORMATION, 0, 0, 0, 0, 0, 0, len_hi, len_lo, 0];
+    let data = super::read(handle, &command, LEN.into())?;
+    // Addresses are in MSF format on CDs
+    let is_cd = super::configuration::media_profile(handle)
+        .ok()
+        .flatten()
+        .is_some_and(|profile| profile.is_cd());
+    parse_disc_info(&data, is_cd)
+}
+
+pub fn parse_disc_info(data: &[u8], is_cd: bool) -> Result<DiscInfo> {
+    if data.len() < 24 {
+        return Err(Error::new(
+            ErrorKind::Unknown,
+            format!("disc information is too short: {} bytes", data.len()),
+        ));
+    }
+    let lead_out = [data[20], data[21], data[22], data[23]];
+    let last_possible_lead_out_lba = match lead_out {
+        [0xff, 0xff, 0xff, 0xff] => None,
+        [_, minute, second, frame] if is_cd => Some(
+            Msf {
+                minute,
+                second,
+                frame,
+            }
+            .to_lba(),
+        ),
+        lba => Some(u32::from_be_bytes(lba)),
+    };
+    Ok(DiscInfo {
+        status: match data[2] & 0x03 {
+            0 => DiscStatus::Empty,
+            1 => DiscStatus::Incomplete,
+            2 => DiscStatus::Complete,
+            _ => DiscStatus::Other,
+        },
+        last_session_state: match (data[2] >> 2) & 0x03 {
+            0 => SessionState::Empty,
+            1 => SessionState::Incomplete,
+            2 => SessionState::Damaged,
+            _ => SessionState::Complete,
+        },
+        erasable: data[2] & 0x10 != 0,
+        sessions: u16::from_be_bytes([data[9], data[4]]),
+        first_track: data[3].into(),
+        first_track_in_last_session: u16::from_be_bytes([data[10], data[5]]),
+        last_track_in_last_session: u16::from_be_bytes([data[11], data[6]]),
+        disc_type: data[8].into(),
+        last_possible_lead_out_lba,
+    })
+}
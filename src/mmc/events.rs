@@ -0,0 +1,138 @@
+use crate::{
+    device::{DriveStatus, PowerState, StatusDetails},
+    error::{Error, ErrorKind, Result},
+    platform::device::DeviceHandle,
+    scsi::opcodes::GET_EVENT_STATUS_NOTIFICATION,
+};
+
+// Only polling is supported, asynchronous notification needs a SCSI target
+const POLLED: u8 = 0x01;
+const HEADER_LEN: usize = 4;
+// Header and the longest event descriptor
+const MAX_LEN: u16 = 8;
+// No event available
+const NEA: u8 = 0x80;
+const CLASS_OPERATIONAL_CHANGE: u8 = 1;
+const CLASS_POWER_MANAGEMENT: u8 = 2;
+const CLASS_MEDIA: u8 = 4;
+const CLASS_DEVICE_BUSY: u8 = 6;
+// Media event codes
+const EJECT_REQUEST: u8 = 1;
+const NEW_MEDIA: u8 = 2;
+const MEDIA_REMOVAL: u8 = 3;
+const MEDIA_CHANGED: u8 = 4;
+// Media status bits
+const DOOR_OPEN: u8 = 0x01;
+const MEDIA_PRESENT: u8 = 0x02;
+
+/// A media event and the current media status, returned by GET EVENT STATUS NOTIFICATION.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MediaEvent {
+    /// The user pressed the eject button.
+    pub eject_requested: bool,
+    /// A medium was inserted, removed or replaced since the last event.
+    pub changed: bool,
+    pub tray_open: bool,
+    pub present: bool,
+}
+
+/// Gets the status of the drive from its media, operational change, power management
+/// and device busy events. Only the media class is required.
+pub fn status_details(handle: &DeviceHandle) -> Result<StatusDetails> {
+    let media = media_event(handle)?;
+    let power = get_event_status(handle, CLASS_POWER_MANAGEMENT)
+        .and_then(|data| parse_power_state(&data))
+        .ok();
+    let busy = [CLASS_DEVICE_BUSY, CLASS_OPERATIONAL_CHANGE]
+        .into_iter()
+        .any(|class| {
+            get_event_status(handle, class)
+                .and_then(|data| parse_busy(&data, class))
+                .unwrap_or(false)
+        });
+    let status = if media.tray_open {
+        DriveStatus::TrayOpen
+    } else if !media.present {
+        DriveStatus::Empty
+    } else if busy {
+        DriveStatus::NotReady
+    } else {
+        DriveStatus::Loaded
+    };
+    Ok(StatusDetails {
+        status,
+        power,
+        busy,
+    })
+}
+
+/// Gets the oldest media event, which is then cleared by the drive.
+pub fn media_event(handle: &DeviceHandle) -> Result<MediaEvent> {
+    parse_media_event(&get_event_status(handle, CLASS_MEDIA)?)
+}
+
+fn get_event_status(handle: &DeviceHandle, class: u8) -> Result<Vec<u8>> {
+    let [len_hi, len_lo] = MAX_LEN.to_be_bytes();
+    let command = [
+        GET_EVENT_STATUS_NOTIFICATION,
+        POLLED,
+        0,
+        0,
+        1 << class,
+        0,
+        0,
+        len_hi,
+        len_lo,
+        0,
+    ];
+    super::read(handle, &command, MAX_LEN.into())
+}
+
+/// Returns the event descriptor in the response to GET EVENT STATUS NOTIFICATION.
+fn event_descriptor(data: &[u8], class: u8) -> Result<&[u8]> {
+    let error = |message: String| Error::new(ErrorKind::UnsupportedOperation, message);
+    if data.len() < HEADER_LEN {
+        return Err(error(format!(
+            "GET EVENT STATUS NOTIFICATION data is too short: {} bytes",
+            data.len()
+        )));
+    }
+    if data[2] & NEA != 0 || data[2] & 0x07 != class {
+        return Err(error(format!(
+            "the drive doesn't report events of class {class}"
+        )));
+    }
+    // The data length doesn't include its own 2 bytes
+    let data_len = u16::from_be_bytes([data[0], data[1]]) as usize + 2;
+    let descriptor = &data[HEADER_LEN..data.len().min(data_len)];
+    if descriptor.len() < 2 {
+        return Err(error("the drive returned an empty event".to_owned()));
+    }
+    Ok(descriptor)
+}
+
+/// Parses the response to GET EVENT STATUS NOTIFICATION with the media class.
+pub fn parse_media_event(data: &[u8]) -> Result<MediaEvent> {
+    let descriptor = event_descriptor(data, CLASS_MEDIA)?;
+    let code = descriptor[0] & 0x0f;
+    Ok(MediaEvent {
+        eject_requested: code == EJECT_REQUEST,
+        changed: matches!(code, NEW_MEDIA | MEDIA_REMOVAL | MEDIA_CHANGED),
+        tray_open: descriptor[1] & DOOR_OPEN != 0,
+        present: descriptor[1] & MEDIA_PRESENT != 0,
+    })
+}
+
+/// Parses the response to GET EVENT STATUS NOTIFICATION with the power management class.
+pub fn parse_power_state(data: &[u8]) -> Result<PowerState> {
+    let descriptor = event_descriptor(data, CLASS_POWER_MANAGEMENT)?;
+    Ok(PowerState::from(descriptor[1]))
+}
+
+/// Parses the response to GET EVENT STATUS NOTIFICATION with the device busy
+/// or operational change class, returning whether the drive is busy.
+pub fn parse_busy(data: &[u8], class: u8) -> Result<bool> {
+    let descriptor = event_descriptor(data, class)?;
+    // Busy status or operational status
+    Ok(descriptor[1] & 0x0f != 0)
+}
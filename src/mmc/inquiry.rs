@@ -0,0 +1,96 @@
+use crate::{
+    device::{DeviceIdentifier, DeviceInfo, DeviceType},
+    error::{Error, ErrorKind, Result},
+    platform::device::DeviceHandle,
+    scsi::opcodes::INQUIRY,
+};
+
+const SUPPORTED_PAGES: u8 = 0x00;
+const UNIT_SERIAL_NUMBER: u8 = 0x80;
+const DEVICE_IDENTIFICATION: u8 = 0x83;
+// Some old devices only look at the low byte of the allocation length
+const ALLOCATION_LEN: u8 = 0xff;
+
+pub fn info(handle: &DeviceHandle) -> Result<DeviceInfo> {
+    let data = super::read(
+        handle,
+        &[INQUIRY, 0, 0, 0, ALLOCATION_LEN, 0],
+        ALLOCATION_LEN.into(),
+    )?;
+    let mut info = parse_standard(&data)?;
+    // The VPD pages are optional, so errors are ignored
+    let pages = vpd_page(handle, SUPPORTED_PAGES).unwrap_or_default();
+    if pages.contains(&UNIT_SERIAL_NUMBER) {
+        info.serial_number = vpd_page(handle, UNIT_SERIAL_NUMBER)
+            .ok()
+            .map(|serial| ascii_field(&serial))
+            .filter(|serial| !serial.is_empty());
+    }
+    if pages.contains(&DEVICE_IDENTIFICATION) {
+        info.identifiers = vpd_page(handle, DEVICE_IDENTIFICATION)
+            .map(|page| parse_identifiers(&page))
+            .unwrap_or_default();
+    }
+    Ok(info)
+}
+
+/// Returns the contents of a VPD page, without its header.
+fn vpd_page(handle: &DeviceHandle, page: u8) -> Result<Vec<u8>> {
+    let data = super::read(
+        handle,
+        &[INQUIRY, 1, page, 0, ALLOCATION_LEN, 0],
+        ALLOCATION_LEN.into(),
+    )?;
+    if data.len() < 4 || data[1] != page {
+        return Err(Error::new(
+            ErrorKind::Unknown,
+            format!("invalid response for VPD page {page:#04x}"),
+        ));
+    }
+    let page_len = u16::from_be_bytes([data[2], data[3]]) as usize;
+    Ok(data[4..data.len().min(4 + page_len)].to_vec())
+}
+
+pub fn parse_standard(data: &[u8]) -> Result<DeviceInfo> {
+    if data.len() < 36 {
+        return Err(Error::new(
+            ErrorKind::Unknown,
+            format!("INQUIRY data is too short: {} bytes", data.len()),
+        ));
+    }
+    Ok(DeviceInfo {
+        vendor: ascii_field(&data[8..16]),
+        product: ascii_field(&data[16..32]),
+        revision: ascii_field(&data[32..36]),
+        device_type: DeviceType::from(data[0]),
+        removable: data[1] & 0x80 != 0,
+        serial_number: None,
+        identifiers: Vec::new(),
+    })
+}
+
+/// Parses the contents of the device identification VPD page.
+pub fn parse_identifiers(mut page: &[u8]) -> Vec<DeviceIdentifier> {
+    let mut identifiers = Vec::new();
+    while page.len() >= 4 {
+        let len = page[3] as usize;
+        let Some(value) = page.get(4..4 + len) else {
+            break;
+        };
+        identifiers.push(DeviceIdentifier {
+            kind: page[1].into(),
+            association: (page[1] >> 4).into(),
+            // Code set 2 is ASCII, 3 is UTF-8
+            is_text: matches!(page[0] & 0x0f, 2 | 3),
+            value: value.to_vec(),
+        });
+        page = &page[4 + len..];
+    }
+    identifiers
+}
+
+fn ascii_field(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes)
+        .trim_matches(|c: char| c == ' ' || c == '\0')
+        .to_owned()
+}
@@ -0,0 +1,121 @@
+use crate::{
+    device::DriveStatus,
+    error::{Error, ErrorKind, Result},
+    platform::device::DeviceHandle,
+    scsi::{
+        opcodes::{LOAD_UNLOAD_MEDIUM, MECHANISM_STATUS},
+        ScsiCommand,
+    },
+};
+
+const HEADER_LEN: usize = 8;
+const SLOT_LEN: usize = 4;
+// Header and the table of the largest changer possible
+const MAX_LEN: u16 = (HEADER_LEN + 255 * SLOT_LEN) as u16;
+// LOAD/UNLOAD MEDIUM flags
+const START: u8 = 0x01;
+const LOAD_UNLOAD: u8 = 0x02;
+
+/// State of the loading mechanism and the slots of a changer,
+/// returned by MECHANISM STATUS.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MechanismStatus {
+    pub door_open: bool,
+    pub current_slot: usize,
+    /// Number of slots, 0 if the drive isn't a changer.
+    pub slot_count: usize,
+    /// Whether each slot has a disc in it.
+    pub slots: Vec<bool>,
+}
+
+pub fn mechanism_status(handle: &DeviceHandle) -> Result<MechanismStatus> {
+    let [len_hi, len_lo] = MAX_LEN.to_be_bytes();
+    let command = [MECHANISM_STATUS, 0, 0, 0, 0, 0, 0, 0, len_hi, len_lo, 0, 0];
+    parse_mechanism_status(&super::read(handle, &command, MAX_LEN.into())?)
+}
+
+/// Parses the response to MECHANISM STATUS.
+pub fn parse_mechanism_status(data: &[u8]) -> Result<MechanismStatus> {
+    if data.len() < HEADER_LEN {
+        return Err(Error::new(
+            ErrorKind::Unknown,
+            format!("MECHANISM STATUS data is too short: {} bytes", data.len()),
+        ));
+    }
+    // The current slot is split between the first two bytes
+    let current_slot = ((data[1] & 0x07) as usize) << 5 | (data[0] & 0x1f) as usize;
+    let slot_count = data[5] as usize;
+    let table_len = u16::from_be_bytes([data[6], data[7]]) as usize;
+    let table = &data[HEADER_LEN..data.len().min(HEADER_LEN + table_len)];
+    let slots = table
+        .chunks_exact(SLOT_LEN)
+        .take(slot_count)
+        .map(|slot| slot[0] & 0x80 != 0)
+        .collect();
+    Ok(MechanismStatus {
+        door_open: data[1] & 0x10 != 0,
+        current_slot,
+        slot_count,
+        slots,
+    })
+}
+
+/// Gets whether the tray is open from MECHANISM STATUS, or from the media
+/// events if the drive doesn't support it.
+pub fn tray_open(handle: &DeviceHandle) -> Result<bool> {
+    if let Ok(status) = mechanism_status(handle) {
+        return Ok(status.door_open);
+    }
+    match super::events::media_event(handle) {
+        Ok(event) => Ok(event.tray_open),
+        Err(err) => Err(Error::new(
+            ErrorKind::UnsupportedOperation,
+            format!("cannot determine whether the tray is open: {err}"),
+        )),
+    }
+}
+
+/// Gets the number of slots of a changer, or 1 if the drive isn't one.
+pub fn changer_slots(handle: &DeviceHandle) -> Result<usize> {
+    match mechanism_status(handle) {
+        Ok(status) => Ok(status.slot_count.max(1)),
+        // MECHANISM STATUS is only mandatory for changers
+        Err(err) if err.kind == ErrorKind::UnsupportedOperation => Ok(1),
+        Err(err) => Err(err),
+    }
+}
+
+pub fn current_slot(handle: &DeviceHandle) -> Result<usize> {
+    Ok(mechanism_status(handle)?.current_slot)
+}
+
+pub fn slot_status(handle: &DeviceHandle, slot: usize) -> Result<DriveStatus> {
+    let status = mechanism_status(handle)?;
+    match status.slots.get(slot) {
+        Some(true) => Ok(DriveStatus::Loaded),
+        Some(false) => Ok(DriveStatus::Empty),
+        None => Err(Error::new(
+            ErrorKind::NotFound,
+            format!("slot {slot} doesn't exist, there are {}", status.slot_count),
+        )),
+    }
+}
+
+/// Moves the disc in a slot of a changer to the drive.
+pub fn load_slot(handle: &DeviceHandle, slot: usize) -> Result<()> {
+    // Slots that don't fit are rejected by the drive
+    let slot = u8::try_from(slot).unwrap_or(u8::MAX);
+    load_unload(handle, LOAD_UNLOAD | START, slot)
+}
+
+/// Returns the disc in the drive to its slot.
+pub fn unload_slot(handle: &DeviceHandle) -> Result<()> {
+    load_unload(handle, LOAD_UNLOAD, 0)
+}
+
+fn load_unload(handle: &DeviceHandle, flags: u8, slot: u8) -> Result<()> {
+    let command = [LOAD_UNLOAD_MEDIUM, 0, 0, 0, flags, 0, 0, 0, slot, 0, 0, 0];
+    handle
+        .execute_scsi(&mut ScsiCommand::new(&command))?
+        .check()
+}
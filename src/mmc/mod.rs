@@ -0,0 +1,37 @@
+// Commands from the SCSI Primary Commands (SPC) and Multimedia Commands (MMC)
+// standards. They only need `DeviceHandle::execute_scsi` so they work on every platform.
+
+pub mod audio;
+pub mod capabilities;
+pub mod capacity;
+pub mod cd_text;
+pub mod configuration;
+pub mod disc_info;
+pub mod events;
+pub mod inquiry;
+pub mod mechanism;
+pub mod mode;
+pub mod read;
+pub mod read_cd;
+pub mod speed;
+pub mod subchannel;
+pub mod toc;
+pub mod unit_ready;
+
+use crate::{
+    error::Result,
+    platform::device::DeviceHandle,
+    scsi::{DataTransfer, ScsiCommand},
+};
+
+/// Sends a command that reads up to `len` bytes from the device.
+pub fn read(handle: &DeviceHandle, cdb: &[u8], len: usize) -> Result<Vec<u8>> {
+    let mut data = vec![0; len];
+    let response = handle.execute_scsi(&mut ScsiCommand {
+        data: DataTransfer::FromDevice(&mut data),
+        ..ScsiCommand::new(cdb)
+    })?;
+    response.check()?;
+    data.truncate(len - response.residual().min(len));
+    Ok(data)
+}
@@ -0,0 +1,83 @@
+use crate::{
+    error::{Error, ErrorKind, Result},
+    platform::device::DeviceHandle,
+    scsi::{
+        opcodes::{MODE_SELECT_10, MODE_SENSE_10},
+        DataTransfer, ScsiCommand,
+    },
+};
+
+const HEADER_LEN: usize = 8;
+// Don't return block descriptors
+const DBD: u8 = 0x08;
+// Page format, required by MMC
+const PF: u8 = 0x10;
+// Page control: current values
+const PC_CURRENT: u8 = 0;
+
+/// Sends MODE SENSE (10) and returns the requested mode page, without the header.
+pub fn mode_sense(handle: &DeviceHandle, page: u8) -> Result<Vec<u8>> {
+    // Get the length first, some drives fail if asked for much more than they have
+    let header = mode_sense_with_len(handle, page, HEADER_LEN as u16)?;
+    let len = match header[..] {
+        // The data length doesn't include its own 2 bytes
+        [len_hi, len_lo, ..] => u16::from_be_bytes([len_hi, len_lo]).saturating_add(2),
+        _ => HEADER_LEN as u16,
+    };
+    let data = mode_sense_with_len(handle, page, len)?;
+    parse_mode_page(&data, page)
+}
+
+fn mode_sense_with_len(handle: &DeviceHandle, page: u8, len: u16) -> Result<Vec<u8>> {
+    let [len_hi, len_lo] = len.to_be_bytes();
+    let command = [
+        MODE_SENSE_10,
+        DBD,
+        PC_CURRENT << 6 | page,
+        0,
+        0,
+        0,
+        0,
+        len_hi,
+        len_lo,
+        0,
+    ];
+    super::read(handle, &command, len.into())
+}
+
+/// Returns the mode page in the response to MODE SENSE (10).
+pub fn parse_mode_page(data: &[u8], page: u8) -> Result<Vec<u8>> {
+    let error = |message: String| Error::new(ErrorKind::Unknown, message);
+    if data.len() < HEADER_LEN {
+        return Err(error(format!(
+            "MODE SENSE data is too short: {} bytes",
+            data.len()
+        )));
+    }
+    // The data length doesn't include its own 2 bytes
+    let data_len = u16::from_be_bytes([data[0], data[1]]) as usize + 2;
+    let descriptors_len = u16::from_be_bytes([data[6], data[7]]) as usize;
+    let data = &data[..data.len().min(data_len)];
+    let page_data = data.get(HEADER_LEN + descriptors_len..).unwrap_or_default();
+    if page_data.len() < 2 || page_data[0] & 0x3f != page {
+        return Err(error(format!("MODE SENSE didn't return page {page:#04x}")));
+    }
+    let page_len = (page_data[1] as usize + 2).min(page_data.len());
+    Ok(page_data[..page_len].to_vec())
+}
+
+/// Sends MODE SELECT (10) with a mode page, as returned by [`mode_sense`].
+pub fn mode_select(handle: &DeviceHandle, page: &[u8]) -> Result<()> {
+    let mut data = vec![0; HEADER_LEN];
+    data.extend_from_slice(page);
+    // The parameters saveable bit is reserved in MODE SELECT
+    data[HEADER_LEN] &= 0x3f;
+    let [len_hi, len_lo] = (data.len() as u16).to_be_bytes();
+    let command = [MODE_SELECT_10, PF, 0, 0, 0, 0, 0, len_hi, len_lo, 0];
+    handle
+        .execute_scsi(&mut ScsiCommand {
+            data: DataTransfer::ToDevice(&data),
+            ..ScsiCommand::new(&command)
+        })?
+        .check()
+}
@@ -0,0 +1,54 @@
+use crate::{
+    error::{Error, ErrorKind, Result},
+    platform::device::DeviceHandle,
+    scsi::{
+        opcodes::{READ_10, READ_16},
+        DataTransfer, ScsiCommand,
+    },
+};
+use std::time::Duration;
+
+// Reading damaged media can take a long time
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Reads `buf.len() / block_len` blocks starting at `lba`.
+pub fn read_blocks(handle: &DeviceHandle, lba: u64, block_len: u32, buf: &mut [u8]) -> Result<()> {
+    let blocks = buf.len() / block_len as usize;
+    let mut command = [0u8; 16];
+    let command: &[u8] = match (u32::try_from(lba), u16::try_from(blocks)) {
+        (Ok(lba), Ok(blocks)) => {
+            command[0] = READ_10;
+            command[2..6].copy_from_slice(&lba.to_be_bytes());
+            command[7..9].copy_from_slice(&blocks.to_be_bytes());
+            &command[..10]
+        }
+        (_, _) => {
+            let blocks = u32::try_from(blocks).map_err(|_| {
+                Error::new(
+                    ErrorKind::UnsupportedOperation,
+                    format!("can't read {blocks} blocks at once"),
+                )
+            })?;
+            command[0] = READ_16;
+            command[2..10].copy_from_slice(&lba.to_be_bytes());
+            command[10..14].copy_from_slice(&blocks.to_be_bytes());
+            &command
+        }
+    };
+    let response = handle.execute_scsi(&mut ScsiCommand {
+        data: DataTransfer::FromDevice(buf),
+        timeout: TIMEOUT,
+        ..ScsiCommand::new(command)
+    })?;
+    response.check()?;
+    if response.residual() != 0 {
+        return Err(Error::new(
+            ErrorKind::Io,
+            format!(
+                "the device returned {} bytes less than requested",
+                response.residual()
+            ),
+        ));
+    }
+    Ok(())
+}
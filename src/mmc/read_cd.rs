@@ -0,0 +1,198 @@
+use crate::{
+    device::{C2ErrorInfo, CdSector, HeaderCodes, ReadCdOptions, SectorType, Subchannel},
+    error::{Error, ErrorKind, Result},
+    platform::device::DeviceHandle,
+    scsi::{opcodes::READ_CD, DataTransfer, ScsiCommand},
+};
+use std::time::Duration;
+
+// Length of a whole sector without C2 or subchannel data
+const RAW_SECTOR_LEN: usize = 2352;
+// Reading damaged media can take a long time
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Reads `buf.len() / 2352` audio sectors starting at `lba`.
+pub fn read_cdda(handle: &DeviceHandle, lba: u32, buf: &mut [u8]) -> Result<()> {
+    let count = (buf.len() / RAW_SECTOR_LEN) as u32;
+    let len = read_cd_into(handle, lba, count, &ReadCdOptions::audio(), buf)?;
+    if len != buf.len() {
+        return Err(Error::new(
+            ErrorKind::Io,
+            format!(
+                "the device returned {} bytes less than requested",
+                buf.len() - len
+            ),
+        ));
+    }
+    Ok(())
+}
+
+pub fn read_cd(
+    handle: &DeviceHandle,
+    lba: u32,
+    count: u32,
+    options: &ReadCdOptions,
+) -> Result<Vec<CdSector>> {
+    let max_sector_len = RAW_SECTOR_LEN + options.c2_errors.len() + options.subchannel.len();
+    let mut data = vec![0; count as usize * max_sector_len];
+    let len = read_cd_into(handle, lba, count, options, &mut data)?;
+    split_sectors(&data[..len], lba, count, options)
+}
+
+/// Sends READ CD and returns the number of bytes transferred.
+fn read_cd_into(
+    handle: &DeviceHandle,
+    lba: u32,
+    count: u32,
+    options: &ReadCdOptions,
+    buf: &mut [u8],
+) -> Result<usize> {
+    let command = command(lba, count, options)?;
+    let response = handle.execute_scsi(&mut ScsiCommand {
+        data: DataTransfer::FromDevice(buf),
+        timeout: TIMEOUT,
+        ..ScsiCommand::new(&command)
+    })?;
+    response.check()?;
+    Ok(buf.len() - response.residual().min(buf.len()))
+}
+
+/// Builds the command descriptor block of READ CD.
+pub fn command(lba: u32, count: u32, options: &ReadCdOptions) -> Result<[u8; 12]> {
+    if count >= 1 << 24 {
+        return Err(Error::new(
+            ErrorKind::UnsupportedOperation,
+            format!("can't read {count} sectors at once"),
+        ));
+    }
+    let sector_type = match options.sector_type {
+        SectorType::Any => 0,
+        SectorType::CdDa => 1,
+        SectorType::Mode1 => 2,
+        SectorType::Mode2Formless => 3,
+        SectorType::Mode2Form1 => 4,
+        SectorType::Mode2Form2 => 5,
+    };
+    let headers = match options.headers {
+        HeaderCodes::None => 0,
+        HeaderCodes::Header => 1,
+        HeaderCodes::SubHeader => 2,
+        HeaderCodes::All => 3,
+    };
+    let c2_errors = match options.c2_errors {
+        C2ErrorInfo::None => 0,
+        C2ErrorInfo::Pointers => 1,
+        C2ErrorInfo::PointersAndBlockBits => 2,
+    };
+    let subchannel = match options.subchannel {
+        Subchannel::None => 0,
+        Subchannel::Raw => 1,
+        Subchannel::Q => 2,
+        Subchannel::Rw => 4,
+    };
+    let fields = u8::from(options.sync) << 7
+        | headers << 5
+        | u8::from(options.user_data) << 4
+        | u8::from(options.edc_ecc) << 3
+        | c2_errors << 1;
+    let [lba_0, lba_1, lba_2, lba_3] = lba.to_be_bytes();
+    let [_, count_hi, count_mid, count_lo] = count.to_be_bytes();
+    Ok([
+        READ_CD,
+        sector_type << 2,
+        lba_0,
+        lba_1,
+        lba_2,
+        lba_3,
+        count_hi,
+        count_mid,
+        count_lo,
+        fields,
+        subchannel,
+        0,
+    ])
+}
+
+/// Splits the data returned by READ CD into sectors. Each one contains the
+/// main data, followed by C2 error information and subchannel data.
+pub fn split_sectors(
+    data: &[u8],
+    lba: u32,
+    count: u32,
+    options: &ReadCdOptions,
+) -> Result<Vec<CdSector>> {
+    if count == 0 {
+        return Ok(Vec::new());
+    }
+    let extra_len = options.c2_errors.len() + options.subchannel.len();
+    // With any sector type, the length of the main data depends on what's on the disc
+    let find_main_len = |extra_len: usize| match options.sector_type {
+        SectorType::Any => [
+            SectorType::CdDa,
+            SectorType::Mode1,
+            SectorType::Mode2Formless,
+            SectorType::Mode2Form1,
+            SectorType::Mode2Form2,
+        ]
+        .into_iter()
+        .map(|sector_type| main_len(sector_type, options))
+        .find(|main_len| data.len() == count as usize * (main_len + extra_len)),
+        sector_type => Some(main_len(sector_type, options))
+            .filter(|main_len| data.len() == count as usize * (main_len + extra_len)),
+    };
+    let Some(main_len) = find_main_len(extra_len) else {
+        // Some drives ignore the request for C2 error pointers or subchannel data
+        if extra_len > 0 && find_main_len(0).is_some() {
+            return Err(Error::new(
+                ErrorKind::UnsupportedOperation,
+                "the drive didn't return the requested C2 error pointers or subchannel data",
+            ));
+        }
+        return Err(Error::new(
+            ErrorKind::Io,
+            format!(
+                "the device returned {} bytes, which don't match {count} requested sectors",
+                data.len()
+            ),
+        ));
+    };
+    let sector_len = main_len + extra_len;
+    let c2_end = main_len + options.c2_errors.len();
+    Ok(data
+        .chunks_exact(sector_len)
+        .zip(lba..)
+        .map(|(sector, lba)| CdSector {
+            lba,
+            data: sector[..main_len].to_vec(),
+            c2_errors: sector[main_len..c2_end].to_vec(),
+            subchannel: sector[c2_end..].to_vec(),
+        })
+        .collect())
+}
+
+/// Length of the main data READ CD returns for each sector of the given type.
+fn main_len(sector_type: SectorType, options: &ReadCdOptions) -> usize {
+    let (header, subheader) = match options.headers {
+        HeaderCodes::None => (false, false),
+        HeaderCodes::Header => (true, false),
+        HeaderCodes::SubHeader => (false, true),
+        HeaderCodes::All => (true, true),
+    };
+    // Audio sectors have no fields, any of them returns the whole sector
+    if sector_type == SectorType::CdDa {
+        let any_field = options.sync || header || subheader || options.user_data || options.edc_ecc;
+        return if any_field { RAW_SECTOR_LEN } else { 0 };
+    }
+    let has_subheader = matches!(sector_type, SectorType::Mode2Form1 | SectorType::Mode2Form2);
+    let (user_data, edc_ecc) = match sector_type {
+        SectorType::Mode1 => (2048, 288),
+        SectorType::Mode2Formless => (2336, 0),
+        SectorType::Mode2Form1 => (2048, 280),
+        _ => (2324, 4),
+    };
+    usize::from(options.sync) * 12
+        + usize::from(header) * 4
+        + usize::from(subheader && has_subheader) * 8
+        + usize::from(options.user_data) * user_data
+        + usize::from(options.edc_ecc) * edc_ecc
+}
@@ -0,0 +1,145 @@
+use crate::{
+    device::DriveSpeeds,
+    error::{Error, ErrorKind, Result},
+    platform::device::DeviceHandle,
+    scsi::{
+        opcodes::{GET_PERFORMANCE, SET_CD_SPEED, SET_STREAMING},
+        DataTransfer, ScsiCommand,
+    },
+};
+
+const HEADER_LEN: usize = 8;
+const DESCRIPTOR_LEN: usize = 16;
+const STREAMING_DESCRIPTOR_LEN: usize = 28;
+const MAX_DESCRIPTORS: u16 = 64;
+// GET PERFORMANCE types
+const TYPE_PERFORMANCE: u8 = 0x00;
+const TYPE_WRITE_SPEED: u8 = 0x03;
+// Nominal read performance, with the tolerance required by MMC
+const NOMINAL_READ: u8 = 0x10;
+// Time in milliseconds used with SET STREAMING, so sizes are in kB/s
+const STREAMING_TIME: u32 = 1000;
+
+pub fn set_speed(handle: &DeviceHandle, read_kbps: u32, write_kbps: u32) -> Result<()> {
+    // SET STREAMING is the only command that works on some DVD and BD drives
+    if set_streaming(handle, read_kbps, write_kbps).is_ok() {
+        return Ok(());
+    }
+    let read = read_kbps.min(u16::MAX.into()) as u16;
+    let write = write_kbps.min(u16::MAX.into()) as u16;
+    let [read_hi, read_lo] = read.to_be_bytes();
+    let [write_hi, write_lo] = write.to_be_bytes();
+    let command = [
+        SET_CD_SPEED,
+        0,
+        read_hi,
+        read_lo,
+        write_hi,
+        write_lo,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+    ];
+    handle
+        .execute_scsi(&mut ScsiCommand::new(&command))?
+        .check()
+}
+
+fn set_streaming(handle: &DeviceHandle, read_kbps: u32, write_kbps: u32) -> Result<()> {
+    // The speed applies to the whole medium
+    let end_lba = super::capacity::capacity(handle)
+        .ok()
+        .and_then(|capacity| u32::try_from(capacity.blocks.saturating_sub(1)).ok())
+        .unwrap_or(u32::MAX);
+    let mut descriptor = [0u8; STREAMING_DESCRIPTOR_LEN];
+    descriptor[8..12].copy_from_slice(&end_lba.to_be_bytes());
+    descriptor[12..16].copy_from_slice(&read_kbps.to_be_bytes());
+    descriptor[16..20].copy_from_slice(&STREAMING_TIME.to_be_bytes());
+    descriptor[20..24].copy_from_slice(&write_kbps.to_be_bytes());
+    descriptor[24..28].copy_from_slice(&STREAMING_TIME.to_be_bytes());
+    let [len_hi, len_lo] = (STREAMING_DESCRIPTOR_LEN as u16).to_be_bytes();
+    let command = [SET_STREAMING, 0, 0, 0, 0, 0, 0, 0, 0, len_hi, len_lo, 0];
+    handle
+        .execute_scsi(&mut ScsiCommand {
+            data: DataTransfer::ToDevice(&descriptor),
+            ..ScsiCommand::new(&command)
+        })?
+        .check()
+}
+
+pub fn speeds(handle: &DeviceHandle) -> Result<DriveSpeeds> {
+    // Drives that can't write may reject the write speed type and only
+    // report read performance, so only fail if neither is available
+    let (write_speeds, performance) = match (
+        get_performance(handle, TYPE_WRITE_SPEED, 0),
+        get_performance(handle, TYPE_PERFORMANCE, NOMINAL_READ),
+    ) {
+        (Err(err), Err(_)) => return Err(err),
+        (write_speeds, performance) => (
+            write_speeds.unwrap_or_default(),
+            performance.unwrap_or_default(),
+        ),
+    };
+    parse_speeds(&write_speeds, &performance)
+}
+
+fn get_performance(handle: &DeviceHandle, performance_type: u8, data_type: u8) -> Result<Vec<u8>> {
+    let [max_hi, max_lo] = MAX_DESCRIPTORS.to_be_bytes();
+    let command = [
+        GET_PERFORMANCE,
+        data_type,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        max_hi,
+        max_lo,
+        performance_type,
+        0,
+    ];
+    let len = HEADER_LEN + MAX_DESCRIPTORS as usize * DESCRIPTOR_LEN;
+    super::read(handle, &command, len)
+}
+
+/// Returns the descriptors in the response to GET PERFORMANCE.
+fn descriptors(data: &[u8]) -> Result<impl Iterator<Item = &[u8]>> {
+    if data.len() < HEADER_LEN {
+        return Err(Error::new(
+            ErrorKind::Unknown,
+            format!("GET PERFORMANCE data is too short: {} bytes", data.len()),
+        ));
+    }
+    // The data length doesn't include its own 4 bytes
+    let data_len = u32::from_be_bytes([data[0], data[1], data[2], data[3]]) as usize + 4;
+    let data = &data[..data.len().min(data_len)];
+    Ok(data[HEADER_LEN..].chunks_exact(DESCRIPTOR_LEN))
+}
+
+/// Parses the responses to GET PERFORMANCE with the write speed and
+/// performance types. Either response may be empty if the drive rejected it.
+pub fn parse_speeds(write_speeds: &[u8], performance: &[u8]) -> Result<DriveSpeeds> {
+    let be_u32 = |bytes: &[u8]| u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    let mut read = Vec::new();
+    let mut write = Vec::new();
+    if !write_speeds.is_empty() {
+        for descriptor in descriptors(write_speeds)? {
+            read.push(be_u32(&descriptor[8..12]));
+            write.push(be_u32(&descriptor[12..16]));
+        }
+    }
+    if !performance.is_empty() {
+        // The speed at the end of the medium is the fastest with constant angular velocity
+        read.extend(descriptors(performance)?.map(|descriptor| be_u32(&descriptor[12..16])));
+    }
+    for speeds in [&mut read, &mut write] {
+        speeds.retain(|&speed| speed != 0);
+        speeds.sort_unstable_by(|a, b| b.cmp(a));
+        speeds.dedup();
+    }
+    Ok(DriveSpeeds { read, write })
+}
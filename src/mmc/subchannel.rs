@@ -0,0 +1,98 @@
+use crate::{
+    device::{AudioState, Msf, PlaybackStatus},
+    error::{Error, ErrorKind, Result},
+    platform::device::DeviceHandle,
+    scsi::opcodes::READ_SUB_CHANNEL,
+};
+
+// Return addresses in MSF format
+const MSF: u8 = 0x02;
+// Return the Q subchannel data
+const SUBQ: u8 = 0x40;
+const FORMAT_CURRENT_POSITION: u8 = 0x01;
+const FORMAT_MCN: u8 = 0x02;
+const FORMAT_ISRC: u8 = 0x03;
+// Header and the longest data format
+const MAX_LEN: u16 = 24;
+
+pub fn read_mcn(handle: &DeviceHandle) -> Result<Option<String>> {
+    parse_mcn(&read_sub_channel(handle, FORMAT_MCN, 0)?)
+}
+
+pub fn read_isrc(handle: &DeviceHandle, track: u8) -> Result<Option<String>> {
+    parse_isrc(&read_sub_channel(handle, FORMAT_ISRC, track)?)
+}
+
+pub fn read_position(handle: &DeviceHandle) -> Result<PlaybackStatus> {
+    parse_position(&read_sub_channel(handle, FORMAT_CURRENT_POSITION, 0)?)
+}
+
+fn read_sub_channel(handle: &DeviceHandle, format: u8, track: u8) -> Result<Vec<u8>> {
+    let [len_hi, len_lo] = MAX_LEN.to_be_bytes();
+    let command = [
+        READ_SUB_CHANNEL,
+        MSF,
+        SUBQ,
+        format,
+        0,
+        0,
+        track,
+        len_hi,
+        len_lo,
+        0,
+    ];
+    super::read(handle, &command, MAX_LEN.into())
+}
+
+/// Parses the response to READ SUB-CHANNEL with the current position format.
+pub fn parse_position(data: &[u8]) -> Result<PlaybackStatus> {
+    let state = AudioState::from(*data.get(1).unwrap_or(&0));
+    let Some(position) = data.get(4..16) else {
+        // Some drives only return the header when there's no position
+        return Ok(PlaybackStatus {
+            state,
+            track: 0,
+            index: 0,
+            absolute: Msf::default(),
+            relative: Msf::default(),
+        });
+    };
+    let msf = |address: &[u8]| Msf {
+        minute: address[1],
+        second: address[2],
+        frame: address[3],
+    };
+    Ok(PlaybackStatus {
+        state,
+        track: position[2],
+        index: position[3],
+        absolute: msf(&position[4..8]),
+        relative: msf(&position[8..12]),
+    })
+}
+
+/// Parses the response to READ SUB-CHANNEL with the Media Catalog Number format.
+pub fn parse_mcn(data: &[u8]) -> Result<Option<String>> {
+    parse_code(data, 22, "Media Catalog Number")
+}
+
+/// Parses the response to READ SUB-CHANNEL with the ISRC format.
+pub fn parse_isrc(data: &[u8]) -> Result<Option<String>> {
+    parse_code(data, 21, "ISRC")
+}
+
+/// Returns the code that ends at `end`, if its valid bit is set.
+fn parse_code(data: &[u8], end: usize, name: &str) -> Result<Option<String>> {
+    let code = data.get(9..end).ok_or_else(|| {
+        Error::new(
+            ErrorKind::Unknown,
+            format!("READ SUB-CHANNEL {name} is too short: {} bytes", data.len()),
+        )
+    })?;
+    let valid = data[8] & 0x80 != 0;
+    // Some drives set the valid bit on discs without a code and return zeros
+    if !valid || code.iter().all(|&char| char == 0 || char == b'0') {
+        return Ok(None);
+    }
+    Ok(Some(String::from_utf8_lossy(code).into_owned()))
+}
@@ -0,0 +1,129 @@
+use crate::{
+    device::{DiscKind, LastSession, Msf, Toc, TocTrack, TrackControl},
+    error::{Error, ErrorKind, Result},
+    platform::device::DeviceHandle,
+    scsi::opcodes::READ_TOC,
+};
+
+const FORMAT_TOC: u8 = 0;
+const FORMAT_SESSION_INFO: u8 = 1;
+const FORMAT_FULL_TOC: u8 = 2;
+const LEAD_OUT: u8 = 0xaa;
+// Full TOC entry with the first track number and the disc type
+const POINT_FIRST_TRACK: u8 = 0xa0;
+const DISC_TYPE_XA: u8 = 0x20;
+
+pub fn read_toc(handle: &DeviceHandle) -> Result<Toc> {
+    let mut toc = parse_toc(&read_toc_format(handle, FORMAT_TOC, 1, 1024)?)?;
+    // Not all drives support the full TOC, if they don't assume there's only one session
+    if let Ok(full_toc) = read_toc_format(handle, FORMAT_FULL_TOC, 1, 4096) {
+        apply_sessions(&mut toc, &full_toc);
+    }
+    Ok(toc)
+}
+
+pub fn disc_kind(handle: &DeviceHandle) -> Result<DiscKind> {
+    let toc = parse_toc(&read_toc_format(handle, FORMAT_TOC, 1, 1024)?)?;
+    let has_audio = toc.tracks.iter().any(|track| track.control.is_audio());
+    let has_data = toc.tracks.iter().any(|track| track.control.is_data());
+    Ok(match (has_audio, has_data) {
+        (true, true) => DiscKind::Mixed,
+        (true, false) => DiscKind::Audio,
+        _ => {
+            let full_toc = read_toc_format(handle, FORMAT_FULL_TOC, 1, 4096).unwrap_or_default();
+            if disc_type(&full_toc) == Some(DISC_TYPE_XA) {
+                DiscKind::XaMode2Form1
+            } else {
+                DiscKind::DataMode1
+            }
+        }
+    })
+}
+
+pub fn last_session(handle: &DeviceHandle) -> Result<LastSession> {
+    parse_session_info(&read_toc_format(handle, FORMAT_SESSION_INFO, 0, 12)?)
+}
+
+/// Sends READ TOC/PMA/ATIP with logical block addresses and returns
+/// the response without its header.
+pub fn read_toc_format(handle: &DeviceHandle, format: u8, number: u8, len: u16) -> Result<Vec<u8>> {
+    let [len_hi, len_lo] = len.to_be_bytes();
+    let command = [READ_TOC, 0, format, 0, 0, 0, number, len_hi, len_lo, 0];
+    let data = super::read(handle, &command, len.into())?;
+    if data.len() < 4 {
+        return Err(Error::new(
+            ErrorKind::Unknown,
+            format!("READ TOC data is too short: {} bytes", data.len()),
+        ));
+    }
+    // The data length doesn't include its own 2 bytes
+    let data_len = u16::from_be_bytes([data[0], data[1]]) as usize + 2;
+    Ok(data[..data.len().min(data_len)].to_vec())
+}
+
+/// Parses the response to READ TOC format 0 with logical block addresses.
+pub fn parse_toc(data: &[u8]) -> Result<Toc> {
+    let mut tracks = Vec::new();
+    let mut lead_out = None;
+    for descriptor in data[4..].chunks_exact(8) {
+        let lba = i32::from_be_bytes([descriptor[4], descriptor[5], descriptor[6], descriptor[7]]);
+        let lba = lba.max(0) as u32;
+        if descriptor[2] == LEAD_OUT {
+            lead_out = Some(lba);
+        } else {
+            tracks.push(TocTrack {
+                number: descriptor[2],
+                session: 1,
+                start_lba: lba,
+                start_msf: Msf::from_lba(lba),
+                control: TrackControl(descriptor[1] & 0x0f),
+            });
+        }
+    }
+    let lead_out_lba = lead_out
+        .ok_or_else(|| Error::new(ErrorKind::Unknown, "the TOC doesn't contain the lead-out"))?;
+    Ok(Toc {
+        first_track: data[2],
+        last_track: data[3],
+        tracks,
+        lead_out_lba,
+        lead_out_msf: Msf::from_lba(lead_out_lba),
+    })
+}
+
+/// Fills in the session of each track using the response to READ TOC format 2.
+pub fn apply_sessions(toc: &mut Toc, full_toc: &[u8]) {
+    for descriptor in full_toc[4..].chunks_exact(11) {
+        let (session, adr, point) = (descriptor[0], descriptor[1] >> 4, descriptor[3]);
+        if adr != 1 {
+            continue;
+        }
+        if let Some(track) = toc.tracks.iter_mut().find(|track| track.number == point) {
+            track.session = session;
+        }
+    }
+}
+
+/// Returns the disc type from the response to READ TOC format 2.
+fn disc_type(full_toc: &[u8]) -> Option<u8> {
+    full_toc
+        .get(4..)?
+        .chunks_exact(11)
+        .find(|descriptor| descriptor[1] >> 4 == 1 && descriptor[3] == POINT_FIRST_TRACK)
+        .map(|descriptor| descriptor[9])
+}
+
+/// Parses the response to READ TOC format 1 with logical block addresses.
+pub fn parse_session_info(data: &[u8]) -> Result<LastSession> {
+    let descriptor = data.get(4..12).ok_or_else(|| {
+        Error::new(
+            ErrorKind::Unknown,
+            format!("READ TOC session info is too short: {} bytes", data.len()),
+        )
+    })?;
+    let lba = i32::from_be_bytes([descriptor[4], descriptor[5], descriptor[6], descriptor[7]]);
+    Ok(LastSession {
+        start_lba: lba.max(0) as u32,
+        multisession: data[3] > 1,
+    })
+}
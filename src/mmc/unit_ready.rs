@@ -0,0 +1,67 @@
+use crate::{
+    device::DriveStatus,
+    error::{Error, ErrorKind, Result},
+    platform::device::DeviceHandle,
+    scsi::{
+        opcodes::{REQUEST_SENSE, TEST_UNIT_READY},
+        ScsiCommand, Sense, SenseKey,
+    },
+};
+
+// Max length of sense data
+const SENSE_LEN: u8 = 252;
+// A unit attention is reported once for each event, like a medium change or a reset
+const MAX_ATTEMPTS: usize = 4;
+
+/// Gets the status of the drive with TEST UNIT READY and,
+/// if it isn't ready, the sense data explaining why.
+pub fn drive_status(handle: &DeviceHandle) -> Result<DriveStatus> {
+    for _ in 0..MAX_ATTEMPTS {
+        let response =
+            handle.execute_scsi(&mut ScsiCommand::new(&[TEST_UNIT_READY, 0, 0, 0, 0, 0]))?;
+        if response.is_good() {
+            return Ok(DriveStatus::Loaded);
+        }
+        // Some drivers don't get the sense data automatically
+        let sense = match response.decoded_sense() {
+            Some(sense) => sense,
+            None => request_sense(handle)?,
+        };
+        if sense.key() == SenseKey::UnitAttention {
+            continue;
+        }
+        return status_from_sense(&sense).ok_or_else(|| sense.into());
+    }
+    Err(Error::new(
+        ErrorKind::Unknown,
+        "the drive keeps reporting unit attention",
+    ))
+}
+
+fn request_sense(handle: &DeviceHandle) -> Result<Sense> {
+    let data = super::read(
+        handle,
+        &[REQUEST_SENSE, 0, 0, 0, SENSE_LEN, 0],
+        SENSE_LEN.into(),
+    )?;
+    Sense::parse(&data).ok_or_else(|| {
+        Error::new(
+            ErrorKind::Unknown,
+            "REQUEST SENSE returned invalid sense data",
+        )
+    })
+}
+
+/// Returns the status of a drive that failed TEST UNIT READY with this sense data,
+/// or `None` if it doesn't tell.
+pub fn status_from_sense(sense: &Sense) -> Option<DriveStatus> {
+    match (sense.key(), sense.asc(), sense.ascq()) {
+        // Medium not present - tray open
+        (_, 0x3a, 0x02) => Some(DriveStatus::TrayOpen),
+        // Medium not present, with the tray closed or an unknown position
+        (_, 0x3a, _) => Some(DriveStatus::Empty),
+        // Becoming ready, initializing or any other reason to not be ready
+        (SenseKey::NotReady, _, _) => Some(DriveStatus::NotReady),
+        _ => None,
+    }
+}
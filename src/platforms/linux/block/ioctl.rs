@@ -0,0 +1,11 @@
+use nix::{ioctl_read_bad, libc::c_int, request_code_read};
+use std::mem::size_of;
+
+// Defined in Linux header linux/fs.h
+// BLKGETSIZE64 is declared with size_t, although it returns a u64
+ioctl_read_bad!(
+    blkgetsize64,
+    request_code_read!(0x12, 114, size_of::<usize>()),
+    u64
+);
+ioctl_read_bad!(blksszget, 0x1268, c_int);
@@ -0,0 +1,62 @@
+mod ioctl;
+
+use self::ioctl::{blkgetsize64, blksszget};
+use crate::{
+    device::Capacity,
+    error::{Error, ErrorKind, Result},
+};
+use nix::{
+    errno::Errno,
+    libc::{self, off64_t},
+};
+use std::{os::unix::prelude::RawFd, thread::sleep, time::Duration};
+
+const MAX_RETRIES: u32 = 100;
+const RETRY_DELAY: Duration = Duration::from_millis(10);
+
+pub fn capacity(fd: RawFd) -> Result<Capacity> {
+    let mut size = 0u64;
+    let mut block_len = 0;
+    unsafe {
+        blkgetsize64(fd, &mut size)?;
+        blksszget(fd, &mut block_len)?;
+    }
+    if block_len <= 0 {
+        return Err(Error::new(
+            ErrorKind::Unknown,
+            format!("BLKSSZGET returned invalid block size: {block_len}"),
+        ));
+    }
+    Ok(Capacity {
+        blocks: size / block_len as u64,
+        block_len: block_len as u32,
+    })
+}
+
+/// Reads exactly `buf.len()` bytes starting at `offset`.
+pub fn read_exact_at(fd: RawFd, mut offset: u64, mut buf: &mut [u8]) -> Result<()> {
+    // The device is opened with O_NONBLOCK, so wait a bit if it's not ready yet
+    let mut retries = 0;
+    while !buf.is_empty() {
+        match pread64(fd, buf, offset) {
+            Ok(0) => return Err(Error::new(ErrorKind::Io, "unexpected end of medium")),
+            Ok(len) => {
+                offset += len as u64;
+                buf = &mut buf[len..];
+            }
+            Err(Errno::EINTR) => continue,
+            Err(Errno::EAGAIN) if retries < MAX_RETRIES => {
+                retries += 1;
+                sleep(RETRY_DELAY);
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(())
+}
+
+/// `pread` with a 64-bit offset, since `off_t` only has 32 bits on some targets.
+fn pread64(fd: RawFd, buf: &mut [u8], offset: u64) -> nix::Result<usize> {
+    let len = unsafe { libc::pread64(fd, buf.as_mut_ptr().cast(), buf.len(), offset as off64_t) };
+    Errno::result(len).map(|len| len as usize)
+}
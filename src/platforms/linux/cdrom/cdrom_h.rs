@@ -0,0 +1,162 @@
+// Taken from Linux header linux/cdrom.h
+
+/// Address format: logical block address
+pub const CDROM_LBA: u8 = 0x01;
+/// Address format: minute, second, frame
+pub const CDROM_MSF: u8 = 0x02;
+/// Speed of a 1x CD in kB/s
+pub const CD_SPEED_1X: u32 = 176;
+/// Track number of the lead-out
+pub const CDROM_LEADOUT: u8 = 0xaa;
+
+#[repr(C)]
+#[derive(Debug, Default)]
+pub struct CdromTochdr {
+    /// start track
+    pub cdth_trk0: u8,
+    /// end track
+    pub cdth_trk1: u8,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union CdromAddr {
+    pub msf: CdromMsf0,
+    pub lba: i32,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CdromMsf0 {
+    pub minute: u8,
+    pub second: u8,
+    pub frame: u8,
+}
+
+#[repr(C)]
+pub struct CdromTocentry {
+    pub cdte_track: u8,
+    /// Bit fields `cdte_adr:4` and `cdte_ctrl:4`
+    pub cdte_adr_ctrl: u8,
+    pub cdte_format: u8,
+    pub cdte_addr: CdromAddr,
+    pub cdte_datamode: u8,
+}
+
+impl CdromTocentry {
+    pub fn new(track: u8, format: u8) -> Self {
+        Self {
+            cdte_track: track,
+            cdte_adr_ctrl: 0,
+            cdte_format: format,
+            cdte_addr: CdromAddr { lba: 0 },
+            cdte_datamode: 0,
+        }
+    }
+
+    pub fn ctrl(&self) -> u8 {
+        // The first bit field is stored in the least significant bits on little endian
+        if cfg!(target_endian = "little") {
+            self.cdte_adr_ctrl >> 4
+        } else {
+            self.cdte_adr_ctrl & 0x0f
+        }
+    }
+}
+
+#[repr(C)]
+pub struct CdromMultisession {
+    /// frame address: start-of-last-session (not the new "frame 16"!).
+    /// Only valid if the "xa_flag" is true.
+    pub addr: CdromAddr,
+    pub xa_flag: u8,
+    /// CDROM_LBA or CDROM_MSF
+    pub addr_format: u8,
+}
+
+#[repr(C)]
+#[derive(Debug, Default)]
+pub struct CdromMcn {
+    pub medium_catalog_number: [u8; 14],
+}
+
+#[repr(C)]
+#[derive(Debug, Default)]
+pub struct CdromMsf {
+    /// start minute
+    pub cdmsf_min0: u8,
+    /// start second
+    pub cdmsf_sec0: u8,
+    /// start frame
+    pub cdmsf_frame0: u8,
+    /// end minute
+    pub cdmsf_min1: u8,
+    /// end second
+    pub cdmsf_sec1: u8,
+    /// end frame
+    pub cdmsf_frame1: u8,
+}
+
+#[repr(C)]
+#[derive(Debug, Default)]
+pub struct CdromTi {
+    /// start track
+    pub cdti_trk0: u8,
+    /// start index
+    pub cdti_ind0: u8,
+    /// end track
+    pub cdti_trk1: u8,
+    /// end index
+    pub cdti_ind1: u8,
+}
+
+#[repr(C)]
+#[derive(Debug, Default)]
+pub struct CdromVolctrl {
+    pub channel0: u8,
+    pub channel1: u8,
+    pub channel2: u8,
+    pub channel3: u8,
+}
+
+#[repr(C)]
+pub struct CdromSubchnl {
+    pub cdsc_format: u8,
+    pub cdsc_audiostatus: u8,
+    /// Bit fields `cdsc_adr:4` and `cdsc_ctrl:4`
+    pub cdsc_adr_ctrl: u8,
+    pub cdsc_trk: u8,
+    pub cdsc_ind: u8,
+    pub cdsc_absaddr: CdromAddr,
+    pub cdsc_reladdr: CdromAddr,
+}
+
+// Special slots for CDROM_DRIVE_STATUS and CDROM_SELECT_DISC
+pub const CDSL_NONE: i32 = i32::MAX - 1;
+pub const CDSL_CURRENT: i32 = i32::MAX;
+
+// Values returned by CDROM_DRIVE_STATUS and CDROM_DISC_STATUS
+pub const CDS_NO_INFO: i32 = 0;
+pub const CDS_NO_DISC: i32 = 1;
+pub const CDS_TRAY_OPEN: i32 = 2;
+pub const CDS_DRIVE_NOT_READY: i32 = 3;
+pub const CDS_DISC_OK: i32 = 4;
+pub const CDS_AUDIO: i32 = 100;
+pub const CDS_DATA_1: i32 = 101;
+pub const CDS_DATA_2: i32 = 102;
+pub const CDS_XA_2_1: i32 = 103;
+pub const CDS_XA_2_2: i32 = 104;
+pub const CDS_MIXED: i32 = 105;
+
+// Capability flags returned by CDROM_GET_CAPABILITY
+pub const CDC_CLOSE_TRAY: i32 = 0x1;
+pub const CDC_OPEN_TRAY: i32 = 0x2;
+pub const CDC_LOCK: i32 = 0x4;
+pub const CDC_SELECT_DISC: i32 = 0x10;
+pub const CDC_MCN: i32 = 0x40;
+pub const CDC_PLAY_AUDIO: i32 = 0x100;
+pub const CDC_CD_R: i32 = 0x2000;
+pub const CDC_CD_RW: i32 = 0x4000;
+pub const CDC_DVD: i32 = 0x8000;
+pub const CDC_DVD_R: i32 = 0x10000;
+pub const CDC_DVD_RAM: i32 = 0x20000;
@@ -1,6 +1,30 @@
-use nix::{ioctl_none_bad, ioctl_write_int_bad};
+use super::cdrom_h::{
+    CdromMcn, CdromMsf, CdromMultisession, CdromSubchnl, CdromTi, CdromTocentry, CdromTochdr,
+    CdromVolctrl,
+};
+use nix::{
+    ioctl_none_bad, ioctl_read_bad, ioctl_readwrite_bad, ioctl_write_int_bad, ioctl_write_ptr_bad,
+};
 
 ioctl_none_bad!(cdromeject, 0x5309);
 ioctl_none_bad!(cdromclosetray, 0x5319);
 ioctl_write_int_bad!(cdrom_drive_status, 0x5326);
 ioctl_write_int_bad!(cdrom_lockdoor, 0x5329);
+ioctl_read_bad!(cdromreadtochdr, 0x5305, CdromTochdr);
+ioctl_readwrite_bad!(cdromreadtocentry, 0x5306, CdromTocentry);
+ioctl_none_bad!(cdrom_disc_status, 0x5327);
+ioctl_readwrite_bad!(cdrommultisession, 0x5310, CdromMultisession);
+ioctl_read_bad!(cdrom_get_mcn, 0x5311, CdromMcn);
+ioctl_write_ptr_bad!(cdromplaymsf, 0x5303, CdromMsf);
+ioctl_write_ptr_bad!(cdromplaytrkind, 0x5304, CdromTi);
+ioctl_none_bad!(cdrompause, 0x5301);
+ioctl_none_bad!(cdromresume, 0x5302);
+ioctl_none_bad!(cdromstop, 0x5307);
+ioctl_write_ptr_bad!(cdromvolctrl, 0x530a, CdromVolctrl);
+ioctl_read_bad!(cdromvolread, 0x5313, CdromVolctrl);
+ioctl_readwrite_bad!(cdromsubchnl, 0x530b, CdromSubchnl);
+ioctl_write_int_bad!(cdrom_select_speed, 0x5322);
+ioctl_none_bad!(cdrom_changer_nslots, 0x5328);
+ioctl_write_int_bad!(cdrom_select_disc, 0x5323);
+ioctl_write_int_bad!(cdrom_media_changed, 0x5325);
+ioctl_none_bad!(cdrom_get_capability, 0x5331);
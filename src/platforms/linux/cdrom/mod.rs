@@ -1,8 +1,21 @@
+mod cdrom_h;
 mod ioctl;
 
-use self::ioctl::{cdrom_drive_status, cdrom_lockdoor, cdromclosetray, cdromeject};
+use self::{
+    cdrom_h::*,
+    ioctl::{
+        cdrom_changer_nslots, cdrom_disc_status, cdrom_drive_status, cdrom_get_capability,
+        cdrom_get_mcn, cdrom_lockdoor, cdrom_media_changed, cdrom_select_disc, cdrom_select_speed,
+        cdromclosetray, cdromeject, cdrommultisession, cdrompause, cdromplaymsf, cdromplaytrkind,
+        cdromreadtocentry, cdromreadtochdr, cdromresume, cdromstop, cdromsubchnl, cdromvolctrl,
+        cdromvolread,
+    },
+};
 use crate::{
-    device::DriveStatus,
+    device::{
+        AudioCapabilities, AudioState, DiscKind, DriveStatus, LastSession, LoadingMechanism,
+        Mechanism, MediaSupport, Msf, PlaybackStatus, Toc, TocTrack, TrackControl, Volume,
+    },
     error::{Error, ErrorKind, Result},
 };
 use std::os::unix::prelude::RawFd;
@@ -28,24 +41,322 @@ pub fn set_ejection_lock(fd: RawFd, locked: bool) -> Result<()> {
     Ok(())
 }
 
-pub fn status(fd: RawFd, slot: i32) -> Result<DriveStatus> {
+pub fn status(fd: RawFd) -> Result<DriveStatus> {
+    drive_status(fd, CDSL_CURRENT)
+}
+
+pub fn slot_status(fd: RawFd, slot: usize) -> Result<DriveStatus> {
+    drive_status(fd, slot_number(slot))
+}
+
+/// Gets the status of a slot of a changer, or of the drive with [`CDSL_CURRENT`].
+fn drive_status(fd: RawFd, slot: i32) -> Result<DriveStatus> {
     let status = unsafe { cdrom_drive_status(fd, slot)? };
-    // From linux/cdrom.h
     match status {
-        0 => Err(Error {
-            code: 0,
-            message: "cannot get drive status".to_owned(),
-            kind: ErrorKind::UnsupportedOperation,
-        }),
-        1 => Ok(DriveStatus::Empty),
-        2 => Ok(DriveStatus::TrayOpen),
-        3 => Ok(DriveStatus::NotReady),
-        4 => Ok(DriveStatus::Loaded),
+        CDS_NO_INFO => Err(Error::new(
+            ErrorKind::UnsupportedOperation,
+            "cannot get drive status",
+        )),
+        CDS_NO_DISC => Ok(DriveStatus::Empty),
+        CDS_TRAY_OPEN => Ok(DriveStatus::TrayOpen),
+        CDS_DRIVE_NOT_READY => Ok(DriveStatus::NotReady),
+        CDS_DISC_OK => Ok(DriveStatus::Loaded),
         // This should never happen
-        _ => Err(Error {
-            code: 0,
-            message: format!("CDROM_DRIVE_STATUS returned unknown status: {status}"),
-            kind: ErrorKind::Unknown,
-        }),
+        _ => Err(Error::new(
+            ErrorKind::Unknown,
+            format!("CDROM_DRIVE_STATUS returned unknown status: {status}"),
+        )),
+    }
+}
+
+pub fn media_changed(fd: RawFd) -> Result<bool> {
+    let changed = unsafe { cdrom_media_changed(fd, CDSL_CURRENT)? };
+    Ok(changed != 0)
+}
+
+pub fn changer_slots(fd: RawFd) -> Result<usize> {
+    let slots = unsafe { cdrom_changer_nslots(fd)? };
+    Ok(slots.max(1) as usize)
+}
+
+pub fn load_slot(fd: RawFd, slot: usize) -> Result<()> {
+    select_disc(fd, slot_number(slot))
+}
+
+pub fn unload_slot(fd: RawFd) -> Result<()> {
+    select_disc(fd, CDSL_NONE)
+}
+
+// Slots that don't fit are rejected by the kernel
+fn slot_number(slot: usize) -> i32 {
+    i32::try_from(slot).unwrap_or(CDSL_NONE - 1)
+}
+
+fn select_disc(fd: RawFd, slot: i32) -> Result<()> {
+    unsafe {
+        cdrom_select_disc(fd, slot)?;
+    }
+    Ok(())
+}
+
+pub fn read_toc(fd: RawFd) -> Result<Toc> {
+    let mut header = CdromTochdr::default();
+    unsafe {
+        cdromreadtochdr(fd, &mut header)?;
+    }
+    let tracks = (header.cdth_trk0..=header.cdth_trk1)
+        .map(|number| {
+            let (lba, control) = read_toc_entry(fd, number)?;
+            Ok(TocTrack {
+                number,
+                // The kernel doesn't tell which session a track belongs to,
+                // the last one is found below
+                session: 1,
+                start_lba: lba,
+                start_msf: Msf::from_lba(lba),
+                control,
+            })
+        })
+        .collect::<Result<_>>()?;
+    let (lead_out_lba, _) = read_toc_entry(fd, CDROM_LEADOUT)?;
+    let mut toc = Toc {
+        first_track: header.cdth_trk0,
+        last_track: header.cdth_trk1,
+        tracks,
+        lead_out_lba,
+        lead_out_msf: Msf::from_lba(lead_out_lba),
+    };
+    // Only the start of the last session is known, enough to find the data
+    // session of an enhanced CD
+    if let Ok(session) = last_session(fd) {
+        if session.multisession {
+            toc.split_last_session(session.start_lba);
+        }
+    }
+    Ok(toc)
+}
+
+fn read_toc_entry(fd: RawFd, track: u8) -> Result<(u32, TrackControl)> {
+    let mut entry = CdromTocentry::new(track, CDROM_LBA);
+    unsafe {
+        cdromreadtocentry(fd, &mut entry)?;
+    }
+    let lba = unsafe { entry.cdte_addr.lba };
+    Ok((lba.max(0) as u32, TrackControl(entry.ctrl())))
+}
+
+pub fn disc_kind(fd: RawFd) -> Result<DiscKind> {
+    let status = unsafe { cdrom_disc_status(fd)? };
+    match status {
+        CDS_AUDIO => Ok(DiscKind::Audio),
+        CDS_DATA_1 => Ok(DiscKind::DataMode1),
+        CDS_DATA_2 => Ok(DiscKind::DataMode2),
+        CDS_XA_2_1 => Ok(DiscKind::XaMode2Form1),
+        CDS_XA_2_2 => Ok(DiscKind::XaMode2Form2),
+        CDS_MIXED => Ok(DiscKind::Mixed),
+        CDS_NO_DISC | CDS_TRAY_OPEN => Err(Error::new(
+            ErrorKind::NoMedium,
+            "there's no disc in the drive",
+        )),
+        CDS_DRIVE_NOT_READY => Err(Error::new(ErrorKind::NotReady, "the drive is not ready")),
+        _ => Err(Error::new(
+            ErrorKind::UnsupportedOperation,
+            format!("CDROM_DISC_STATUS returned unexpected status: {status}"),
+        )),
+    }
+}
+
+pub fn last_session(fd: RawFd) -> Result<LastSession> {
+    let mut multisession = CdromMultisession {
+        addr: CdromAddr { lba: 0 },
+        xa_flag: 0,
+        addr_format: CDROM_LBA,
+    };
+    unsafe {
+        cdrommultisession(fd, &mut multisession)?;
+    }
+    let multisession_disc = multisession.xa_flag != 0;
+    let start_lba = if multisession_disc {
+        unsafe { multisession.addr.lba.max(0) as u32 }
+    } else {
+        0
+    };
+    Ok(LastSession {
+        start_lba,
+        multisession: multisession_disc,
+    })
+}
+
+pub fn mcn(fd: RawFd) -> Result<Option<String>> {
+    let mut mcn = CdromMcn::default();
+    unsafe {
+        cdrom_get_mcn(fd, &mut mcn)?;
     }
+    let digits = &mcn.medium_catalog_number[..13];
+    // Discs without a catalog number return zeros
+    if digits.iter().all(|&digit| digit == 0 || digit == b'0') {
+        return Ok(None);
+    }
+    Ok(Some(String::from_utf8_lossy(digits).into_owned()))
+}
+
+pub fn play_msf(fd: RawFd, start: Msf, end: Msf) -> Result<()> {
+    let msf = CdromMsf {
+        cdmsf_min0: start.minute,
+        cdmsf_sec0: start.second,
+        cdmsf_frame0: start.frame,
+        cdmsf_min1: end.minute,
+        cdmsf_sec1: end.second,
+        cdmsf_frame1: end.frame,
+    };
+    unsafe {
+        cdromplaymsf(fd, &msf)?;
+    }
+    Ok(())
+}
+
+pub fn play_tracks(fd: RawFd, first: u8, last: u8) -> Result<()> {
+    let ti = CdromTi {
+        cdti_trk0: first,
+        cdti_ind0: 1,
+        cdti_trk1: last,
+        cdti_ind1: 1,
+    };
+    unsafe {
+        cdromplaytrkind(fd, &ti)?;
+    }
+    Ok(())
+}
+
+pub fn pause_audio(fd: RawFd, pause: bool) -> Result<()> {
+    unsafe {
+        if pause {
+            cdrompause(fd)?;
+        } else {
+            cdromresume(fd)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn stop_audio(fd: RawFd) -> Result<()> {
+    unsafe {
+        cdromstop(fd)?;
+    }
+    Ok(())
+}
+
+pub fn volume(fd: RawFd) -> Result<Volume> {
+    let mut volctrl = CdromVolctrl::default();
+    unsafe {
+        cdromvolread(fd, &mut volctrl)?;
+    }
+    Ok(Volume {
+        channels: [
+            volctrl.channel0,
+            volctrl.channel1,
+            volctrl.channel2,
+            volctrl.channel3,
+        ],
+    })
+}
+
+pub fn set_volume(fd: RawFd, volume: Volume) -> Result<()> {
+    let [channel0, channel1, channel2, channel3] = volume.channels;
+    let volctrl = CdromVolctrl {
+        channel0,
+        channel1,
+        channel2,
+        channel3,
+    };
+    unsafe {
+        cdromvolctrl(fd, &volctrl)?;
+    }
+    Ok(())
+}
+
+pub fn playback_status(fd: RawFd) -> Result<PlaybackStatus> {
+    let mut subchnl = CdromSubchnl {
+        cdsc_format: CDROM_MSF,
+        cdsc_audiostatus: 0,
+        cdsc_adr_ctrl: 0,
+        cdsc_trk: 0,
+        cdsc_ind: 0,
+        cdsc_absaddr: CdromAddr { lba: 0 },
+        cdsc_reladdr: CdromAddr { lba: 0 },
+    };
+    unsafe {
+        cdromsubchnl(fd, &mut subchnl)?;
+    }
+    let msf = |address: CdromAddr| {
+        let msf = unsafe { address.msf };
+        Msf {
+            minute: msf.minute,
+            second: msf.second,
+            frame: msf.frame,
+        }
+    };
+    Ok(PlaybackStatus {
+        state: AudioState::from(subchnl.cdsc_audiostatus),
+        track: subchnl.cdsc_trk,
+        index: subchnl.cdsc_ind,
+        absolute: msf(subchnl.cdsc_absaddr),
+        relative: msf(subchnl.cdsc_reladdr),
+    })
+}
+
+pub fn set_speed(fd: RawFd, read_kbps: u32) -> Result<()> {
+    // The kernel takes the speed as a multiple of 1x CD speed, with 0 meaning the fastest
+    let speed = if read_kbps == u32::MAX {
+        0
+    } else {
+        (read_kbps / CD_SPEED_1X).max(1)
+    };
+    unsafe {
+        cdrom_select_speed(fd, speed as i32)?;
+    }
+    Ok(())
+}
+
+pub fn mechanism(fd: RawFd) -> Result<Mechanism> {
+    let capabilities = unsafe { cdrom_get_capability(fd)? };
+    let has = |flag: i32| capabilities & flag != 0;
+    let loading = if has(CDC_SELECT_DISC) {
+        LoadingMechanism::Changer
+    } else if has(CDC_CLOSE_TRAY) {
+        LoadingMechanism::Tray
+    } else {
+        LoadingMechanism::Unknown
+    };
+    Ok(Mechanism {
+        loading,
+        can_eject: has(CDC_OPEN_TRAY),
+        can_retract: has(CDC_CLOSE_TRAY),
+        can_lock: has(CDC_LOCK),
+        // The kernel doesn't report these
+        locked: false,
+        prevent_jumper: false,
+        // Drives that write a type of disc can also read it
+        read: MediaSupport {
+            cd_r: has(CDC_CD_R) || has(CDC_CD_RW) || has(CDC_DVD),
+            cd_rw: has(CDC_CD_RW) || has(CDC_DVD),
+            dvd_rom: has(CDC_DVD),
+            dvd_r: has(CDC_DVD_R),
+            dvd_ram: has(CDC_DVD_RAM),
+        },
+        write: MediaSupport {
+            cd_r: has(CDC_CD_R),
+            cd_rw: has(CDC_CD_RW),
+            dvd_rom: false,
+            dvd_r: has(CDC_DVD_R),
+            dvd_ram: has(CDC_DVD_RAM),
+        },
+        audio: AudioCapabilities {
+            play: has(CDC_PLAY_AUDIO),
+            mcn: has(CDC_MCN),
+            ..Default::default()
+        },
+        max_read_speed: None,
+        max_write_speed: None,
+    })
 }
@@ -1,7 +1,11 @@
-use super::{cdrom, scsi};
+use super::{block, cdrom, scsi};
 use crate::{
-    device::DriveStatus,
+    device::{
+        Capacity, DiscKind, DriveStatus, LastSession, Mechanism, Msf, PlaybackStatus, Toc, Volume,
+    },
     error::{ErrorKind, Result},
+    mmc,
+    scsi::{ScsiCommand, ScsiResponse},
 };
 use nix::{
     fcntl::{open, OFlag},
@@ -64,7 +68,166 @@ impl DeviceHandle {
     }
 
     pub fn status(&self) -> Result<DriveStatus> {
-        cdrom::status(self.0, 0)
+        if let Ok(status) = cdrom::status(self.0) {
+            return Ok(status);
+        }
+        if let Ok(details) = mmc::events::status_details(self) {
+            return Ok(details.status);
+        }
+        mmc::unit_ready::drive_status(self)
+    }
+
+    pub fn tray_open(&self) -> Result<bool> {
+        if let Ok(status) = cdrom::status(self.0) {
+            return Ok(status.tray_open());
+        }
+        mmc::mechanism::tray_open(self)
+    }
+
+    pub fn media_changed(&self) -> Result<bool> {
+        cdrom::media_changed(self.0)
+    }
+
+    pub fn changer_slots(&self) -> Result<usize> {
+        if let Ok(slots) = cdrom::changer_slots(self.0) {
+            return Ok(slots);
+        }
+        mmc::mechanism::changer_slots(self)
+    }
+
+    pub fn current_slot(&self) -> Result<usize> {
+        mmc::mechanism::current_slot(self)
+    }
+
+    pub fn slot_status(&self, slot: usize) -> Result<DriveStatus> {
+        if let Ok(status) = cdrom::slot_status(self.0, slot) {
+            return Ok(status);
+        }
+        mmc::mechanism::slot_status(self, slot)
+    }
+
+    pub fn load_slot(&self, slot: usize) -> Result<()> {
+        if cdrom::load_slot(self.0, slot).is_ok() {
+            return Ok(());
+        }
+        mmc::mechanism::load_slot(self, slot)
+    }
+
+    pub fn unload_slot(&self) -> Result<()> {
+        if cdrom::unload_slot(self.0).is_ok() {
+            return Ok(());
+        }
+        mmc::mechanism::unload_slot(self)
+    }
+
+    pub fn toc(&self) -> Result<Toc> {
+        if let Ok(toc) = mmc::toc::read_toc(self) {
+            return Ok(toc);
+        }
+        cdrom::read_toc(self.0)
+    }
+
+    pub fn disc_kind(&self) -> Result<DiscKind> {
+        if let Ok(kind) = mmc::toc::disc_kind(self) {
+            return Ok(kind);
+        }
+        cdrom::disc_kind(self.0)
+    }
+
+    pub fn last_session(&self) -> Result<LastSession> {
+        if let Ok(session) = mmc::toc::last_session(self) {
+            return Ok(session);
+        }
+        cdrom::last_session(self.0)
+    }
+
+    pub fn mcn(&self) -> Result<Option<String>> {
+        if let Ok(mcn) = mmc::subchannel::read_mcn(self) {
+            return Ok(mcn);
+        }
+        cdrom::mcn(self.0)
+    }
+
+    pub fn play_msf(&self, start: Msf, end: Msf) -> Result<()> {
+        if cdrom::play_msf(self.0, start, end).is_ok() {
+            return Ok(());
+        }
+        mmc::audio::play_msf(self, start, end)
+    }
+
+    pub fn play_tracks(&self, first: u8, last: u8) -> Result<()> {
+        if cdrom::play_tracks(self.0, first, last).is_ok() {
+            return Ok(());
+        }
+        mmc::audio::play_tracks(self, first, last)
+    }
+
+    pub fn pause_audio(&self, pause: bool) -> Result<()> {
+        if cdrom::pause_audio(self.0, pause).is_ok() {
+            return Ok(());
+        }
+        mmc::audio::pause_audio(self, pause)
+    }
+
+    pub fn stop_audio(&self) -> Result<()> {
+        if cdrom::stop_audio(self.0).is_ok() {
+            return Ok(());
+        }
+        mmc::audio::stop_audio(self)
+    }
+
+    pub fn volume(&self) -> Result<Volume> {
+        if let Ok(volume) = cdrom::volume(self.0) {
+            return Ok(volume);
+        }
+        mmc::audio::volume(self)
+    }
+
+    pub fn set_volume(&self, volume: Volume) -> Result<()> {
+        if cdrom::set_volume(self.0, volume).is_ok() {
+            return Ok(());
+        }
+        mmc::audio::set_volume(self, volume)
+    }
+
+    pub fn playback_status(&self) -> Result<PlaybackStatus> {
+        if let Ok(status) = cdrom::playback_status(self.0) {
+            return Ok(status);
+        }
+        mmc::subchannel::read_position(self)
+    }
+
+    pub fn set_speed(&self, read_kbps: u32, write_kbps: u32) -> Result<()> {
+        if mmc::speed::set_speed(self, read_kbps, write_kbps).is_ok() {
+            return Ok(());
+        }
+        cdrom::set_speed(self.0, read_kbps)
+    }
+
+    pub fn mechanism(&self) -> Result<Mechanism> {
+        if let Ok(mechanism) = mmc::capabilities::mechanism(self) {
+            return Ok(mechanism);
+        }
+        cdrom::mechanism(self.0)
+    }
+
+    pub fn capacity(&self) -> Result<Capacity> {
+        if let Ok(capacity) = mmc::capacity::capacity(self) {
+            return Ok(capacity);
+        }
+        block::capacity(self.0)
+    }
+
+    pub fn read_blocks(&self, lba: u64, block_len: u32, buf: &mut [u8]) -> Result<()> {
+        if block::read_exact_at(self.0, lba * block_len as u64, buf).is_ok() {
+            return Ok(());
+        }
+        // Also gets sense data explaining the error
+        mmc::read::read_blocks(self, lba, block_len, buf)
+    }
+
+    pub fn execute_scsi(&self, command: &mut ScsiCommand) -> Result<ScsiResponse> {
+        scsi::execute(self.0, command)
     }
 }
 
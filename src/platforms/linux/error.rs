@@ -1,11 +1,16 @@
 use crate::error::{Error, ErrorKind};
 
+impl Error {
+    pub(crate) fn from_raw_os_error(code: i32) -> Self {
+        nix::errno::Errno::from_i32(code).into()
+    }
+}
+
 impl From<nix::errno::Errno> for Error {
     fn from(e: nix::errno::Errno) -> Self {
         Self {
             code: e as i32,
-            message: e.to_string(),
-            kind: e.into(),
+            ..Self::new(e.into(), e.to_string())
         }
     }
 }
@@ -18,6 +23,12 @@ impl From<nix::errno::Errno> for ErrorKind {
             Errno::EPERM | Errno::EACCES => Self::AccessDenied,
             Errno::ENOENT => Self::NotFound,
             Errno::ENAMETOOLONG => Self::InvalidPath,
+            Errno::EBUSY => Self::Busy,
+            Errno::ENOMEDIUM => Self::NoMedium,
+            // Returned by some drivers while the medium is spinning up
+            Errno::ENXIO => Self::NotReady,
+            Errno::ETIMEDOUT => Self::TimedOut,
+            Errno::EIO => Self::Io,
             _ => Self::Unknown,
         }
     }
@@ -1,3 +1,4 @@
+mod block;
 mod cdrom;
 pub mod device;
 pub mod discovery;
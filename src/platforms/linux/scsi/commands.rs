@@ -1,4 +0,0 @@
-// Defined in Linux header scsi/scsi.h
-
-pub const START_STOP: u8 = 0x1b;
-pub const ALLOW_MEDIUM_REMOVAL: u8 = 0x1e;
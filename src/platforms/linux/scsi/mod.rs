@@ -1,71 +1,73 @@
-mod commands;
 mod ioctl;
 mod sg_io_hdr;
 
 use self::{
-    commands::{ALLOW_MEDIUM_REMOVAL, START_STOP},
     ioctl::sg_io,
     sg_io_hdr::{DxferDirection, SgIoHdr},
 };
-use crate::error::Result;
-use std::os::unix::prelude::RawFd;
+use crate::{
+    error::{Error, ErrorKind, Result},
+    scsi::{
+        opcodes::{ALLOW_MEDIUM_REMOVAL, START_STOP},
+        DataTransfer, ScsiCommand, ScsiResponse, MAX_CDB_LEN,
+    },
+};
+use std::{os::unix::prelude::RawFd, ptr::null_mut, time::Duration};
 
-pub fn eject(fd: RawFd) -> Result<()> {
-    let eject_command: &mut [u8] = &mut [START_STOP, 0, 0, 0, 2, 0];
+pub fn execute(fd: RawFd, command: &mut ScsiCommand) -> Result<ScsiResponse> {
+    if command.cdb.is_empty() || command.cdb.len() > MAX_CDB_LEN {
+        return Err(Error::new(
+            ErrorKind::UnsupportedOperation,
+            format!("invalid SCSI command length: {}", command.cdb.len()),
+        ));
+    }
+    let mut cdb = [0u8; MAX_CDB_LEN];
+    cdb[..command.cdb.len()].copy_from_slice(command.cdb);
     let mut sense_buffer = [0u8; 32];
+    let (dxfer_direction, dxferp, dxfer_len) = match &mut command.data {
+        DataTransfer::None => (DxferDirection::None, null_mut(), 0),
+        DataTransfer::FromDevice(buf) => (DxferDirection::FromDev, buf.as_mut_ptr(), buf.len()),
+        // SG_IO won't write to the buffer when the direction is ToDev
+        DataTransfer::ToDevice(buf) => (DxferDirection::ToDev, buf.as_ptr() as *mut u8, buf.len()),
+    };
     let mut request = SgIoHdr {
-        cmdp: eject_command.as_mut_ptr(),
-        cmd_len: eject_command.len() as u8,
-        dxfer_direction: DxferDirection::None,
-        dxferp: [0u8; 2].as_mut_ptr() as *mut _,
-        dxfer_len: 0,
+        cmdp: cdb.as_mut_ptr(),
+        cmd_len: command.cdb.len() as u8,
+        dxfer_direction,
+        dxferp: dxferp as *mut _,
+        dxfer_len: dxfer_len.try_into().map_err(|_| {
+            Error::new(
+                ErrorKind::UnsupportedOperation,
+                format!("SCSI data buffer is too big: {dxfer_len} bytes"),
+            )
+        })?,
         sbp: sense_buffer.as_mut_ptr() as *mut _,
         mx_sb_len: sense_buffer.len() as u8,
-        timeout: 10 * 1000,
+        timeout: command.timeout.as_millis().try_into().unwrap_or(u32::MAX),
         ..Default::default()
     };
     unsafe {
         sg_io(fd, (&mut request) as *mut _)?;
     }
-    request.check_errors()
+    request.check_errors()?;
+    let sense_len = (request.sb_len_wr as usize).min(sense_buffer.len());
+    Ok(ScsiResponse {
+        status: request.status,
+        residual: request.resid.max(0) as usize,
+        duration: Duration::from_millis(request.duration.into()),
+        sense: sense_buffer[..sense_len].to_vec(),
+    })
+}
+
+pub fn eject(fd: RawFd) -> Result<()> {
+    execute(fd, &mut ScsiCommand::new(&[START_STOP, 0, 0, 0, 2, 0]))?.check()
 }
 
 pub fn retract(fd: RawFd) -> Result<()> {
-    let eject_command: &mut [u8] = &mut [START_STOP, 0, 0, 0, 3, 0];
-    let mut sense_buffer = [0u8; 32];
-    let mut request = SgIoHdr {
-        cmdp: eject_command.as_mut_ptr(),
-        cmd_len: eject_command.len() as u8,
-        dxfer_direction: DxferDirection::None,
-        dxferp: [0u8; 2].as_mut_ptr() as *mut _,
-        dxfer_len: 0,
-        sbp: sense_buffer.as_mut_ptr() as *mut _,
-        mx_sb_len: sense_buffer.len() as u8,
-        timeout: 10 * 1000,
-        ..Default::default()
-    };
-    unsafe {
-        sg_io(fd, (&mut request) as *mut _)?;
-    }
-    request.check_errors()
+    execute(fd, &mut ScsiCommand::new(&[START_STOP, 0, 0, 0, 3, 0]))?.check()
 }
 
 pub fn set_ejection_lock(fd: RawFd, locked: bool) -> Result<()> {
-    let allow_removal_command: &mut [u8] = &mut [ALLOW_MEDIUM_REMOVAL, 0, 0, 0, locked as u8, 0];
-    let mut sense_buffer = [0u8; 32];
-    let mut request = SgIoHdr {
-        cmdp: allow_removal_command.as_mut_ptr(),
-        cmd_len: allow_removal_command.len() as u8,
-        dxfer_direction: DxferDirection::None,
-        dxferp: [0u8; 2].as_mut_ptr() as *mut _,
-        dxfer_len: 0,
-        sbp: sense_buffer.as_mut_ptr() as *mut _,
-        mx_sb_len: sense_buffer.len() as u8,
-        timeout: 10 * 1000,
-        ..Default::default()
-    };
-    unsafe {
-        sg_io(fd, (&mut request) as *mut _)?;
-    }
-    request.check_errors()
+    let command = [ALLOW_MEDIUM_REMOVAL, 0, 0, 0, locked as u8, 0];
+    execute(fd, &mut ScsiCommand::new(&command))?.check()
 }
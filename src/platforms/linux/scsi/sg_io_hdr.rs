@@ -82,18 +82,27 @@ impl Default for SgIoHdr {
 
 impl SgIoHdr {
     pub fn check_errors(&self) -> Result<()> {
+        let driver_status = self.driver_status & DRIVER_MASK;
         if self.host_status != 0 {
-            Err(Error {
-                code: 0,
-                message: format!("SG_IO failed with host_status = {}", self.host_status),
-                kind: ErrorKind::Unknown,
-            })
-        } else if self.driver_status != 0 {
-            Err(Error {
-                code: 0,
-                message: format!("SG_IO failed with driver_status = {}", self.driver_status),
-                kind: ErrorKind::Unknown,
-            })
+            let kind = match self.host_status {
+                DID_BUS_BUSY => ErrorKind::Busy,
+                DID_TIME_OUT => ErrorKind::TimedOut,
+                _ => ErrorKind::Unknown,
+            };
+            Err(Error::new(
+                kind,
+                format!("SG_IO failed with host_status = {}", self.host_status),
+            ))
+        } else if driver_status != DRIVER_OK && driver_status != DRIVER_SENSE {
+            let kind = match driver_status {
+                DRIVER_BUSY => ErrorKind::Busy,
+                DRIVER_TIMEOUT => ErrorKind::TimedOut,
+                _ => ErrorKind::Unknown,
+            };
+            Err(Error::new(
+                kind,
+                format!("SG_IO failed with driver_status = {}", self.driver_status),
+            ))
         } else {
             Ok(())
         }
@@ -102,6 +111,16 @@ impl SgIoHdr {
 
 pub const SG_INTERFACE_ID_ORIG: i32 = 'S' as i32;
 
+const DID_BUS_BUSY: u16 = 0x02;
+const DID_TIME_OUT: u16 = 0x03;
+
+const DRIVER_OK: u16 = 0x00;
+const DRIVER_BUSY: u16 = 0x01;
+const DRIVER_TIMEOUT: u16 = 0x06;
+// Sense data is returned in the sense buffer and checked by the caller
+const DRIVER_SENSE: u16 = 0x08;
+const DRIVER_MASK: u16 = 0x0f;
+
 #[repr(i32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[allow(dead_code)]
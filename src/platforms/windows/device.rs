@@ -1,7 +1,14 @@
-use super::{scsi::ScsiPassThroughDirectSenseBuffer, util::pcwstr};
+use super::{
+    scsi::{ScsiDataBuffer, ScsiPassThroughDirectSenseBuffer},
+    util::pcwstr,
+};
 use crate::{
-    device::DriveStatus,
+    device::{
+        Capacity, DiscKind, DriveStatus, LastSession, Mechanism, Msf, PlaybackStatus, Toc, Volume,
+    },
     error::{Error, ErrorKind, Result},
+    mmc,
+    scsi::{DataTransfer, ScsiCommand, ScsiResponse, MAX_CDB_LEN},
 };
 use std::{
     ffi::OsString,
@@ -9,6 +16,7 @@ use std::{
     os::raw::c_void,
     path::Path,
     ptr::{null, null_mut},
+    time::Instant,
 };
 use windows::{
     Win32::System::Ioctl::{IOCTL_STORAGE_EJECT_MEDIA, IOCTL_STORAGE_LOAD_MEDIA2},
@@ -19,7 +27,10 @@ use windows::{
                 CreateFileW, FILE_ACCESS_FLAGS, FILE_FLAGS_AND_ATTRIBUTES, FILE_GENERIC_READ,
                 FILE_GENERIC_WRITE, FILE_SHARE_READ, FILE_SHARE_WRITE, OPEN_EXISTING,
             },
-            IscsiDisc::{IOCTL_SCSI_PASS_THROUGH_DIRECT, SCSI_IOCTL_DATA_IN},
+            IscsiDisc::{
+                IOCTL_SCSI_PASS_THROUGH_DIRECT, SCSI_IOCTL_DATA_IN, SCSI_IOCTL_DATA_OUT,
+                SCSI_IOCTL_DATA_UNSPECIFIED,
+            },
         },
         System::{Ioctl::IOCTL_STORAGE_MEDIA_REMOVAL, IO::DeviceIoControl},
     },
@@ -86,15 +97,126 @@ impl DeviceHandle {
     }
 
     pub fn status(&self) -> Result<DriveStatus> {
-        const DATA_LEN: usize = 8;
-        let mut request = ScsiPassThroughDirectSenseBuffer::<DATA_LEN>::new();
-        request.sptd.DataIn = SCSI_IOCTL_DATA_IN as u8;
-        request.sptd.CdbLength = 10;
-        request.sptd.Cdb[0] = 0x4a; // Command: GET EVENT/STATUS NOTIFICATION
-        request.sptd.Cdb[1] = 1; // Polled
-        request.sptd.Cdb[4] = 0x10; // Event class: media
-        request.sptd.Cdb[7] = (DATA_LEN >> 8) as u8;
-        request.sptd.Cdb[8] = DATA_LEN as u8;
+        if let Ok(details) = mmc::events::status_details(self) {
+            return Ok(details.status);
+        }
+        mmc::unit_ready::drive_status(self)
+    }
+
+    pub fn tray_open(&self) -> Result<bool> {
+        mmc::mechanism::tray_open(self)
+    }
+
+    pub fn media_changed(&self) -> Result<bool> {
+        // Media changes are only reported by GET EVENT STATUS NOTIFICATION
+        Err(Error::new(
+            ErrorKind::UnsupportedOperation,
+            "media change detection requires GET EVENT STATUS NOTIFICATION",
+        ))
+    }
+
+    pub fn changer_slots(&self) -> Result<usize> {
+        mmc::mechanism::changer_slots(self)
+    }
+
+    pub fn current_slot(&self) -> Result<usize> {
+        mmc::mechanism::current_slot(self)
+    }
+
+    pub fn slot_status(&self, slot: usize) -> Result<DriveStatus> {
+        mmc::mechanism::slot_status(self, slot)
+    }
+
+    pub fn load_slot(&self, slot: usize) -> Result<()> {
+        mmc::mechanism::load_slot(self, slot)
+    }
+
+    pub fn unload_slot(&self) -> Result<()> {
+        mmc::mechanism::unload_slot(self)
+    }
+
+    pub fn toc(&self) -> Result<Toc> {
+        mmc::toc::read_toc(self)
+    }
+
+    pub fn disc_kind(&self) -> Result<DiscKind> {
+        mmc::toc::disc_kind(self)
+    }
+
+    pub fn last_session(&self) -> Result<LastSession> {
+        mmc::toc::last_session(self)
+    }
+
+    pub fn mcn(&self) -> Result<Option<String>> {
+        mmc::subchannel::read_mcn(self)
+    }
+
+    pub fn play_msf(&self, start: Msf, end: Msf) -> Result<()> {
+        mmc::audio::play_msf(self, start, end)
+    }
+
+    pub fn play_tracks(&self, first: u8, last: u8) -> Result<()> {
+        mmc::audio::play_tracks(self, first, last)
+    }
+
+    pub fn pause_audio(&self, pause: bool) -> Result<()> {
+        mmc::audio::pause_audio(self, pause)
+    }
+
+    pub fn stop_audio(&self) -> Result<()> {
+        mmc::audio::stop_audio(self)
+    }
+
+    pub fn volume(&self) -> Result<Volume> {
+        mmc::audio::volume(self)
+    }
+
+    pub fn set_volume(&self, volume: Volume) -> Result<()> {
+        mmc::audio::set_volume(self, volume)
+    }
+
+    pub fn playback_status(&self) -> Result<PlaybackStatus> {
+        mmc::subchannel::read_position(self)
+    }
+
+    pub fn set_speed(&self, read_kbps: u32, write_kbps: u32) -> Result<()> {
+        mmc::speed::set_speed(self, read_kbps, write_kbps)
+    }
+
+    pub fn mechanism(&self) -> Result<Mechanism> {
+        mmc::capabilities::mechanism(self)
+    }
+
+    pub fn capacity(&self) -> Result<Capacity> {
+        mmc::capacity::capacity(self)
+    }
+
+    pub fn read_blocks(&self, lba: u64, block_len: u32, buf: &mut [u8]) -> Result<()> {
+        mmc::read::read_blocks(self, lba, block_len, buf)
+    }
+
+    pub fn execute_scsi(&self, command: &mut ScsiCommand) -> Result<ScsiResponse> {
+        if command.cdb.is_empty() || command.cdb.len() > MAX_CDB_LEN {
+            return Err(Error::new(
+                ErrorKind::UnsupportedOperation,
+                format!("invalid SCSI command length: {}", command.cdb.len()),
+            ));
+        }
+        let mut data = ScsiDataBuffer::new(command.data.len());
+        let data_in = match &command.data {
+            DataTransfer::None => SCSI_IOCTL_DATA_UNSPECIFIED,
+            DataTransfer::FromDevice(_) => SCSI_IOCTL_DATA_IN,
+            DataTransfer::ToDevice(buf) => {
+                data.copy_from_slice(buf);
+                SCSI_IOCTL_DATA_OUT
+            }
+        };
+        // The timeout is in seconds and can't be 0
+        let timeout_secs = command.timeout.as_millis().div_ceil(1000);
+        let timeout_secs = timeout_secs.clamp(1, u32::MAX as u128) as u32;
+        let mut request =
+            ScsiPassThroughDirectSenseBuffer::new(command.cdb, data_in, &mut data, timeout_secs);
+        let start = Instant::now();
         unsafe {
             self.ioctl(
                 IOCTL_SCSI_PASS_THROUGH_DIRECT,
@@ -102,12 +224,18 @@ impl DeviceHandle {
                 Some((&mut request as *mut _ as *mut c_void, size_of_val(&request))),
             )?;
         }
-        let media_status = request.data[5];
-        match media_status {
-            0 => Ok(DriveStatus::Empty),
-            2 => Ok(DriveStatus::Loaded),
-            _ => Ok(DriveStatus::TrayOpen),
+        let duration = start.elapsed();
+        let transferred = (request.sptd.DataTransferLength as usize).min(data.len());
+        if let DataTransfer::FromDevice(buf) = &mut command.data {
+            buf[..transferred].copy_from_slice(&data[..transferred]);
         }
+        let sense_len = (request.sptd.SenseInfoLength as usize).min(request.sense_buffer.len());
+        Ok(ScsiResponse {
+            status: request.sptd.ScsiStatus,
+            residual: data.len() - transferred,
+            duration,
+            sense: request.sense_buffer[..sense_len].to_vec(),
+        })
     }
 
     unsafe fn ioctl(
@@ -1,7 +1,9 @@
 use crate::error::{Error, ErrorKind};
 use windows::Win32::Foundation::{
-    GetLastError, ERROR_ACCESS_DENIED, ERROR_FILE_NOT_FOUND, ERROR_INVALID_NAME,
-    ERROR_PATH_NOT_FOUND, WIN32_ERROR,
+    GetLastError, ERROR_ACCESS_DENIED, ERROR_BUSY, ERROR_CRC, ERROR_DEVICE_IN_USE,
+    ERROR_FILE_NOT_FOUND, ERROR_INVALID_FUNCTION, ERROR_INVALID_NAME, ERROR_IO_DEVICE,
+    ERROR_NOT_READY, ERROR_NOT_SUPPORTED, ERROR_NO_MEDIA_IN_DRIVE, ERROR_PATH_NOT_FOUND,
+    ERROR_SEM_TIMEOUT, ERROR_SHARING_VIOLATION, ERROR_TIMEOUT, WIN32_ERROR,
 };
 
 impl Error {
@@ -14,11 +16,17 @@ impl Error {
         }
     }
 
+    pub(crate) fn from_raw_os_error(code: i32) -> Self {
+        Self::from_os_err(WIN32_ERROR(code as u32))
+    }
+
     pub(crate) fn from_os_err(err: WIN32_ERROR) -> Self {
         Self {
             code: err.0 as i32,
-            message: err.to_hresult().message().to_string_lossy(),
-            kind: ErrorKind::from_os_err(err),
+            ..Self::new(
+                ErrorKind::from_os_err(err),
+                err.to_hresult().message().to_string_lossy(),
+            )
         }
     }
 }
@@ -28,8 +36,10 @@ impl From<windows::core::Error> for Error {
         let err_code = e.code().0 & 0xFF;
         Self {
             code: err_code,
-            message: e.message().to_string_lossy(),
-            kind: ErrorKind::from_os_err(WIN32_ERROR(err_code as u32)),
+            ..Self::new(
+                ErrorKind::from_os_err(WIN32_ERROR(err_code as u32)),
+                e.message().to_string_lossy(),
+            )
         }
     }
 }
@@ -40,6 +50,12 @@ impl ErrorKind {
             ERROR_FILE_NOT_FOUND | ERROR_PATH_NOT_FOUND => Self::NotFound,
             ERROR_ACCESS_DENIED => Self::AccessDenied,
             ERROR_INVALID_NAME => Self::InvalidPath,
+            ERROR_INVALID_FUNCTION | ERROR_NOT_SUPPORTED => Self::UnsupportedOperation,
+            ERROR_BUSY | ERROR_DEVICE_IN_USE | ERROR_SHARING_VIOLATION => Self::Busy,
+            ERROR_NO_MEDIA_IN_DRIVE => Self::NoMedium,
+            ERROR_NOT_READY => Self::NotReady,
+            ERROR_SEM_TIMEOUT | ERROR_TIMEOUT => Self::TimedOut,
+            ERROR_IO_DEVICE | ERROR_CRC => Self::Io,
             _ => Self::Unknown,
         }
     }
@@ -0,0 +1,57 @@
+use std::time::Duration;
+
+/// Max length of a command descriptor block supported by all platforms.
+pub(crate) const MAX_CDB_LEN: usize = 16;
+
+/// A SCSI command ready to be sent with
+/// [`Device::execute_scsi`][crate::device::Device::execute_scsi].
+///
+/// See the [`scsi`][crate::scsi] module docs for an example.
+#[derive(Debug)]
+pub struct ScsiCommand<'a> {
+    /// The command descriptor block. Must be between 1 and 16 bytes long.
+    pub cdb: &'a [u8],
+    /// Direction of the data transfer and buffer used for it.
+    pub data: DataTransfer<'a>,
+    /// Time after which the command will be aborted.
+    pub timeout: Duration,
+}
+
+impl<'a> ScsiCommand<'a> {
+    /// Creates a command that doesn't transfer any data and
+    /// has a timeout of 10 seconds.
+    pub fn new(cdb: &'a [u8]) -> Self {
+        Self {
+            cdb,
+            data: DataTransfer::None,
+            timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+/// Data transferred by a [`ScsiCommand`].
+#[derive(Debug)]
+pub enum DataTransfer<'a> {
+    /// The command doesn't transfer data, e.g. TEST UNIT READY.
+    None,
+    /// The device will write to this buffer, e.g. INQUIRY.
+    FromDevice(&'a mut [u8]),
+    /// The contents of this buffer will be sent to the device, e.g. MODE SELECT.
+    ToDevice(&'a [u8]),
+}
+
+impl DataTransfer<'_> {
+    /// Returns the length of the buffer, or 0 if there's none.
+    pub fn len(&self) -> usize {
+        match self {
+            Self::None => 0,
+            Self::FromDevice(buf) => buf.len(),
+            Self::ToDevice(buf) => buf.len(),
+        }
+    }
+
+    /// Returns whether no data will be transferred.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -0,0 +1,34 @@
+//! Send raw SCSI/MMC commands to a device.
+//!
+//! This is useful to issue commands that this crate doesn't wrap yet.
+//! Build a [`ScsiCommand`] and pass it to
+//! [`Device::execute_scsi`][crate::device::Device::execute_scsi].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use eject::{device::Device, scsi::{DataTransfer, ScsiCommand}};
+//!
+//! let cdrom = Device::open("/dev/cdrom")?;
+//! // Send an INQUIRY command
+//! let mut data = [0u8; 36];
+//! let response = cdrom.execute_scsi(&mut ScsiCommand {
+//!     data: DataTransfer::FromDevice(&mut data),
+//!     ..ScsiCommand::new(&[0x12, 0, 0, 0, 36, 0])
+//! })?;
+//! response.check()?;
+//! println!("Vendor: {}", String::from_utf8_lossy(&data[8..16]));
+//! # eject::error::Result::Ok(())
+//! ```
+
+mod command;
+pub(crate) mod opcodes;
+mod response;
+mod sense;
+
+pub(crate) use self::command::MAX_CDB_LEN;
+pub use self::{
+    command::{DataTransfer, ScsiCommand},
+    response::ScsiResponse,
+    sense::{Sense, SenseKey},
+};
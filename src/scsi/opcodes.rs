@@ -0,0 +1,27 @@
+// Defined in the SPC and MMC standards
+
+pub const START_STOP: u8 = 0x1b;
+pub const ALLOW_MEDIUM_REMOVAL: u8 = 0x1e;
+pub const INQUIRY: u8 = 0x12;
+pub const GET_CONFIGURATION: u8 = 0x46;
+pub const READ_TOC: u8 = 0x43;
+pub const READ_DISC_INFORMATION: u8 = 0x51;
+pub const READ_CAPACITY_10: u8 = 0x25;
+pub const SERVICE_ACTION_IN_16: u8 = 0x9e;
+pub const READ_10: u8 = 0x28;
+pub const READ_16: u8 = 0x88;
+pub const READ_CD: u8 = 0xbe;
+pub const READ_SUB_CHANNEL: u8 = 0x42;
+pub const PLAY_AUDIO_MSF: u8 = 0x47;
+pub const PAUSE_RESUME: u8 = 0x4b;
+pub const STOP_PLAY_SCAN: u8 = 0x4e;
+pub const MODE_SENSE_10: u8 = 0x5a;
+pub const MODE_SELECT_10: u8 = 0x55;
+pub const SET_CD_SPEED: u8 = 0xbb;
+pub const SET_STREAMING: u8 = 0xb6;
+pub const GET_PERFORMANCE: u8 = 0xac;
+pub const LOAD_UNLOAD_MEDIUM: u8 = 0xa6;
+pub const MECHANISM_STATUS: u8 = 0xbd;
+pub const GET_EVENT_STATUS_NOTIFICATION: u8 = 0x4a;
+pub const TEST_UNIT_READY: u8 = 0x00;
+pub const REQUEST_SENSE: u8 = 0x03;
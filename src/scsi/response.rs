@@ -0,0 +1,71 @@
+use super::Sense;
+use crate::error::{Error, ErrorKind, Result};
+use std::time::Duration;
+
+// From SAM-5
+pub(crate) const STATUS_GOOD: u8 = 0x00;
+pub(crate) const STATUS_CONDITION_MET: u8 = 0x04;
+
+/// The result of a command sent with
+/// [`Device::execute_scsi`][crate::device::Device::execute_scsi].
+///
+/// A response is returned even if the device reported an error.
+/// Use [`check`][Self::check] to turn those into an [`Error`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScsiResponse {
+    pub(crate) status: u8,
+    pub(crate) residual: usize,
+    pub(crate) duration: Duration,
+    pub(crate) sense: Vec<u8>,
+}
+
+impl ScsiResponse {
+    /// Returns the SCSI status byte sent by the device, e.g. 0 for GOOD
+    /// or 2 for CHECK CONDITION.
+    pub fn status(&self) -> u8 {
+        self.status
+    }
+
+    /// Returns the number of bytes of the data buffer that were not transferred.
+    pub fn residual(&self) -> usize {
+        self.residual
+    }
+
+    /// Returns how long the command took to complete.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the raw sense data sent by the device, which will be empty
+    /// unless the status is CHECK CONDITION.
+    pub fn sense(&self) -> &[u8] {
+        &self.sense
+    }
+
+    /// Returns whether the command completed successfully.
+    pub fn is_good(&self) -> bool {
+        matches!(self.status, STATUS_GOOD | STATUS_CONDITION_MET)
+    }
+
+    /// Decodes the sense data, if there's any.
+    pub fn decoded_sense(&self) -> Option<Sense> {
+        Sense::parse(&self.sense)
+    }
+
+    /// Returns an error if the command didn't complete successfully.
+    ///
+    /// If the device sent sense data, it will be available through
+    /// [`Error::sense`][crate::error::Error::sense].
+    pub fn check(&self) -> Result<()> {
+        if self.is_good() {
+            Ok(())
+        } else if let Some(sense) = self.decoded_sense() {
+            Err(sense.into())
+        } else {
+            Err(Error::new(
+                ErrorKind::Unknown,
+                format!("SCSI command failed with status = {:#04x}", self.status),
+            ))
+        }
+    }
+}
@@ -0,0 +1,304 @@
+use crate::error::ErrorKind;
+use std::fmt;
+
+/// Decoded SCSI sense data, which explains why a command failed.
+///
+/// Both fixed (response codes 0x70/0x71) and descriptor (0x72/0x73)
+/// formats are supported.
+///
+/// # Example
+///
+/// ```
+/// use eject::scsi::{Sense, SenseKey};
+///
+/// // Fixed format sense data: NOT READY, medium not present
+/// let raw = [0x70, 0, 0x02, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0x3a, 0x00];
+/// let sense = Sense::parse(&raw).unwrap();
+/// assert_eq!(sense.key(), SenseKey::NotReady);
+/// assert_eq!(sense.description(), "medium not present");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Sense {
+    response_code: u8,
+    key: SenseKey,
+    asc: u8,
+    ascq: u8,
+    information: Option<u64>,
+    raw: Vec<u8>,
+}
+
+impl Sense {
+    /// Decodes sense data returned by a device.
+    ///
+    /// Returns `None` if it's empty or in an unknown format.
+    pub fn parse(raw: &[u8]) -> Option<Self> {
+        let response_code = raw.first()? & 0x7f;
+        let byte = |i: usize| raw.get(i).copied().unwrap_or(0);
+        match response_code {
+            0x70 | 0x71 => {
+                let valid = byte(0) & 0x80 != 0;
+                let information = if valid && raw.len() >= 7 {
+                    Some(u32::from_be_bytes([byte(3), byte(4), byte(5), byte(6)]).into())
+                } else {
+                    None
+                };
+                Some(Self {
+                    response_code,
+                    key: SenseKey::from(byte(2)),
+                    asc: byte(12),
+                    ascq: byte(13),
+                    information,
+                    raw: raw.to_vec(),
+                })
+            }
+            0x72 | 0x73 => Some(Self {
+                response_code,
+                key: SenseKey::from(byte(1)),
+                asc: byte(2),
+                ascq: byte(3),
+                information: Self::find_information_descriptor(raw),
+                raw: raw.to_vec(),
+            }),
+            _ => None,
+        }
+    }
+
+    fn find_information_descriptor(raw: &[u8]) -> Option<u64> {
+        let additional_len = *raw.get(7)? as usize;
+        let end = raw.len().min(8 + additional_len);
+        let mut i = 8;
+        while i + 2 <= end {
+            let descriptor_len = raw[i + 1] as usize + 2;
+            let descriptor = raw.get(i..(i + descriptor_len).min(end))?;
+            // Information descriptor with the VALID bit set
+            if descriptor[0] == 0x00 && descriptor.len() >= 12 && descriptor[2] & 0x80 != 0 {
+                return Some(u64::from_be_bytes(descriptor[4..12].try_into().ok()?));
+            }
+            i += descriptor_len;
+        }
+        None
+    }
+
+    /// Returns the response code, which indicates the format of the sense data
+    /// and whether it refers to the current command or a previous one.
+    pub fn response_code(&self) -> u8 {
+        self.response_code
+    }
+
+    /// Returns whether the sense data is in descriptor format.
+    pub fn is_descriptor_format(&self) -> bool {
+        matches!(self.response_code, 0x72 | 0x73)
+    }
+
+    /// Returns whether this error belongs to a previous command.
+    pub fn is_deferred(&self) -> bool {
+        matches!(self.response_code, 0x71 | 0x73)
+    }
+
+    /// Returns the general category of the error.
+    pub fn key(&self) -> SenseKey {
+        self.key
+    }
+
+    /// Returns the additional sense code.
+    pub fn asc(&self) -> u8 {
+        self.asc
+    }
+
+    /// Returns the additional sense code qualifier.
+    pub fn ascq(&self) -> u8 {
+        self.ascq
+    }
+
+    /// Returns the contents of the information field if it's valid.
+    /// For read errors it usually contains the failing logical block address.
+    pub fn information(&self) -> Option<u64> {
+        self.information
+    }
+
+    /// Returns the sense data as sent by the device.
+    pub fn raw(&self) -> &[u8] {
+        &self.raw
+    }
+
+    /// Returns a human readable description of the additional sense code,
+    /// or of the sense key if the code is not known.
+    pub fn description(&self) -> &'static str {
+        asc_description(self.asc, self.ascq).unwrap_or_else(|| self.key.description())
+    }
+
+    pub(crate) fn error_kind(&self) -> ErrorKind {
+        match (self.key, self.asc, self.ascq) {
+            (_, 0x53, 0x02) => ErrorKind::EjectionLocked,
+            (_, 0x3a, _) => ErrorKind::NoMedium,
+            (_, 0x08, 0x01) => ErrorKind::TimedOut,
+            (SenseKey::NotReady, _, _) => ErrorKind::NotReady,
+            (SenseKey::IllegalRequest, 0x20 | 0x24, _) => ErrorKind::UnsupportedOperation,
+            (SenseKey::DataProtect, _, _) => ErrorKind::AccessDenied,
+            (SenseKey::MediumError | SenseKey::HardwareError, _, _) => ErrorKind::Io,
+            _ => ErrorKind::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for Sense {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} (ASC = {:#04x}, ASCQ = {:#04x})",
+            self.key.description(),
+            self.description(),
+            self.asc,
+            self.ascq
+        )
+    }
+}
+
+/// General category of a SCSI error, as reported in its [`Sense`] data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SenseKey {
+    /// There's no error to report.
+    NoSense,
+    /// The command succeeded after the device took some recovery action.
+    RecoveredError,
+    /// The device can't be accessed, e.g. because there's no medium.
+    NotReady,
+    /// The command failed because of a flaw in the medium.
+    MediumError,
+    /// The device detected a hardware failure.
+    HardwareError,
+    /// The command or its parameters are not valid.
+    IllegalRequest,
+    /// The medium may have changed or the device was reset.
+    UnitAttention,
+    /// The medium is protected against the attempted operation.
+    DataProtect,
+    /// A blank medium was encountered while reading or a non blank one while writing.
+    BlankCheck,
+    /// Vendor specific condition.
+    VendorSpecific,
+    /// A copy operation was aborted.
+    CopyAborted,
+    /// The device aborted the command.
+    AbortedCommand,
+    /// The end of the medium was reached.
+    VolumeOverflow,
+    /// The source data didn't match the data on the medium.
+    Miscompare,
+    /// The command completed.
+    Completed,
+    /// A reserved or obsolete sense key.
+    Reserved,
+}
+
+impl SenseKey {
+    /// Returns a human readable description of this sense key.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::NoSense => "no sense",
+            Self::RecoveredError => "recovered error",
+            Self::NotReady => "not ready",
+            Self::MediumError => "medium error",
+            Self::HardwareError => "hardware error",
+            Self::IllegalRequest => "illegal request",
+            Self::UnitAttention => "unit attention",
+            Self::DataProtect => "data protect",
+            Self::BlankCheck => "blank check",
+            Self::VendorSpecific => "vendor specific",
+            Self::CopyAborted => "copy aborted",
+            Self::AbortedCommand => "aborted command",
+            Self::VolumeOverflow => "volume overflow",
+            Self::Miscompare => "miscompare",
+            Self::Completed => "completed",
+            Self::Reserved => "reserved",
+        }
+    }
+}
+
+impl From<u8> for SenseKey {
+    /// Converts the low 4 bits of `key` to a sense key.
+    fn from(key: u8) -> Self {
+        match key & 0x0f {
+            0x0 => Self::NoSense,
+            0x1 => Self::RecoveredError,
+            0x2 => Self::NotReady,
+            0x3 => Self::MediumError,
+            0x4 => Self::HardwareError,
+            0x5 => Self::IllegalRequest,
+            0x6 => Self::UnitAttention,
+            0x7 => Self::DataProtect,
+            0x8 => Self::BlankCheck,
+            0x9 => Self::VendorSpecific,
+            0xa => Self::CopyAborted,
+            0xb => Self::AbortedCommand,
+            0xd => Self::VolumeOverflow,
+            0xe => Self::Miscompare,
+            0xf => Self::Completed,
+            _ => Self::Reserved,
+        }
+    }
+}
+
+// From SPC-4 and MMC-6, only the ones likely to be returned by removable media drives
+fn asc_description(asc: u8, ascq: u8) -> Option<&'static str> {
+    Some(match (asc, ascq) {
+        (0x00, 0x00) => "no additional sense information",
+        (0x00, 0x11) => "audio play operation in progress",
+        (0x00, 0x12) => "audio play operation paused",
+        (0x00, 0x13) => "audio play operation successfully completed",
+        (0x00, 0x14) => "audio play operation stopped due to error",
+        (0x02, 0x00) => "no seek complete",
+        (0x04, 0x00) => "logical unit not ready, cause not reportable",
+        (0x04, 0x01) => "logical unit is in process of becoming ready",
+        (0x04, 0x02) => "logical unit not ready, initializing command required",
+        (0x04, 0x03) => "logical unit not ready, manual intervention required",
+        (0x04, 0x04) => "logical unit not ready, format in progress",
+        (0x04, 0x07) => "logical unit not ready, operation in progress",
+        (0x04, 0x08) => "logical unit not ready, long write in progress",
+        (0x05, 0x00) => "logical unit does not respond to selection",
+        (0x06, 0x00) => "no reference position found",
+        (0x08, 0x00) => "logical unit communication failure",
+        (0x08, 0x01) => "logical unit communication timeout",
+        (0x09, 0x00) => "track following error",
+        (0x0c, 0x00) => "write error",
+        (0x11, 0x00) => "unrecovered read error",
+        (0x11, 0x05) => "L-EC uncorrectable error",
+        (0x11, 0x06) => "CIRC unrecovered error",
+        (0x15, 0x00) => "random positioning error",
+        (0x1a, 0x00) => "parameter list length error",
+        (0x20, 0x00) => "invalid command operation code",
+        (0x21, 0x00) => "logical block address out of range",
+        (0x21, 0x02) => "invalid address for write",
+        (0x24, 0x00) => "invalid field in CDB",
+        (0x25, 0x00) => "logical unit not supported",
+        (0x26, 0x00) => "invalid field in parameter list",
+        (0x27, 0x00) => "write protected",
+        (0x28, 0x00) => "not ready to ready change, medium may have changed",
+        (0x28, 0x01) => "import or export element accessed",
+        (0x29, 0x00) => "power on, reset, or bus device reset occurred",
+        (0x2a, 0x00) => "parameters changed",
+        (0x2c, 0x00) => "command sequence error",
+        (0x30, 0x00) => "incompatible medium installed",
+        (0x30, 0x01) => "cannot read medium, unknown format",
+        (0x30, 0x02) => "cannot read medium, incompatible format",
+        (0x30, 0x05) => "cannot write medium, incompatible format",
+        (0x31, 0x00) => "medium format corrupted",
+        (0x3a, 0x00) => "medium not present",
+        (0x3a, 0x01) => "medium not present, tray closed",
+        (0x3a, 0x02) => "medium not present, tray open",
+        (0x3e, 0x00) => "logical unit has not self-configured yet",
+        (0x44, 0x00) => "internal target failure",
+        (0x53, 0x00) => "media load or eject failed",
+        (0x53, 0x02) => "medium removal prevented",
+        (0x57, 0x00) => "unable to recover table of contents",
+        (0x5a, 0x01) => "operator medium removal request",
+        (0x5e, 0x00) => "low power condition on",
+        (0x63, 0x00) => "end of user area encountered on this track",
+        (0x64, 0x00) => "illegal mode for this track",
+        (0x6f, 0x00) => "copy protection key exchange failure, authentication failure",
+        (0x6f, 0x03) => "read of scrambled sector without authentication",
+        (0x72, 0x00) => "session fixation error",
+        (0x73, 0x00) => "CD control error",
+        _ => return None,
+    })
+}
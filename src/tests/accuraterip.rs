@@ -0,0 +1,84 @@
+use crate::{
+    audio::{
+        AccurateRipChecksum, AccurateRipDatabase, AccurateRipId, ChecksumVersion, Crc32,
+        TrackChecksums,
+    },
+    device::{Msf, Toc, TocTrack, TrackControl},
+};
+
+fn two_track_toc() -> Toc {
+    let track = |number, start_lba| TocTrack {
+        number,
+        session: 1,
+        start_lba,
+        start_msf: Msf::from_lba(start_lba),
+        control: TrackControl(0),
+    };
+    Toc {
+        first_track: 1,
+        last_track: 2,
+        tracks: vec![track(1, 0), track(2, 1000)],
+        lead_out_lba: 2000,
+        lead_out_msf: Msf::from_lba(2000),
+    }
+}
+
+#[test]
+fn accuraterip_id() {
+    let id = AccurateRipId::from_toc(&two_track_toc());
+    assert_eq!((id.track_count, id.id1, id.id2), (2, 3000, 8001));
+    assert_eq!(id.freedb_id, 0x08001a02);
+    assert_eq!(id.file_name(), "dBAR-002-00000bb8-00001f41-08001a02.bin");
+    assert_eq!(
+        id.url(),
+        "http://www.accuraterip.com/accuraterip/8/b/b/dBAR-002-00000bb8-00001f41-08001a02.bin"
+    );
+}
+
+#[test]
+fn accuraterip_checksum() {
+    // The first 2939 samples of the first track are skipped
+    let mut checksum = AccurateRipChecksum::new(3000, true, false);
+    checksum.update(&[0xff; 2939 * 4]);
+    checksum.update(&[2, 0, 0, 0]);
+    assert_eq!(checksum.v1(), 2 * 2940);
+    // Multiplying by the position overflows into the v2 checksum
+    let mut checksum = AccurateRipChecksum::new(2, false, false);
+    checksum.update(&[0, 0, 0, 0, 0, 0, 0, 0x80]);
+    assert_eq!(checksum.v1(), 0);
+    assert_eq!(checksum.v2(), 1);
+}
+
+#[test]
+fn crc32() {
+    let mut crc = Crc32::new();
+    assert_eq!(crc.value(), 0);
+    crc.update(b"The quick brown fox ");
+    crc.update(b"jumps over the lazy dog");
+    assert_eq!(crc.value(), 0x414fa339);
+}
+
+#[test]
+fn accuraterip_database() {
+    let id = AccurateRipId::from_toc(&two_track_toc());
+    let mut file = vec![2];
+    for value in [id.id1, id.id2, id.freedb_id] {
+        file.extend_from_slice(&value.to_le_bytes());
+    }
+    for (confidence, checksum) in [(7u8, 0x1234u32), (3, 0x5678)] {
+        file.push(confidence);
+        file.extend_from_slice(&checksum.to_le_bytes());
+        file.extend_from_slice(&[0; 4]);
+    }
+    let database = AccurateRipDatabase::parse(&file).unwrap();
+    assert!(database.contains(&id));
+    let checksums = TrackChecksums {
+        crc32: 0,
+        accuraterip_v1: 0x5678,
+        accuraterip_v2: 0x9999,
+    };
+    let found = database.verify(&id, 2, &checksums).unwrap();
+    assert_eq!((found.version, found.confidence), (ChecksumVersion::V1, 3));
+    assert_eq!(database.verify(&id, 1, &checksums), None);
+    assert!(AccurateRipDatabase::parse(&file[..20]).is_err());
+}
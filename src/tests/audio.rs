@@ -0,0 +1,28 @@
+use crate::audio::{write_wav_header, RipProgress};
+
+#[test]
+fn wav_header() {
+    let mut header = Vec::new();
+    write_wav_header(&mut header, 2352 * 75).unwrap();
+    assert_eq!(header.len(), 44);
+    assert_eq!(&header[0..4], b"RIFF");
+    assert_eq!(&header[4..8], &(2352u32 * 75 + 36).to_le_bytes());
+    assert_eq!(&header[8..16], b"WAVEfmt ");
+    // PCM, 2 channels, 44100 Hz, 176400 bytes per second, 4 byte blocks, 16 bits
+    assert_eq!(
+        &header[20..36],
+        &[1, 0, 2, 0, 0x44, 0xac, 0, 0, 0x10, 0xb1, 2, 0, 4, 0, 16, 0]
+    );
+    assert_eq!(&header[36..40], b"data");
+    assert_eq!(&header[40..44], &(2352u32 * 75).to_le_bytes());
+}
+
+#[test]
+fn progress_fraction() {
+    let progress = RipProgress {
+        track: 1,
+        sectors_read: 25,
+        track_sectors: 100,
+    };
+    assert_eq!(progress.fraction(), 0.25);
+}
@@ -0,0 +1,20 @@
+use crate::mmc::capacity::{parse_capacity_10, parse_capacity_16};
+
+#[test]
+fn read_capacity_10() {
+    let capacity = parse_capacity_10(&[0, 0x05, 0x7a, 0x8f, 0, 0, 0x08, 0]).unwrap();
+    assert_eq!(capacity.blocks, 359_056);
+    assert_eq!(capacity.block_len, 2048);
+    assert_eq!(capacity.bytes(), 735_346_688);
+}
+
+#[test]
+fn read_capacity_16() {
+    let mut data = [0u8; 32];
+    data[3] = 1;
+    data[7] = 0xff;
+    data[10] = 0x02;
+    let capacity = parse_capacity_16(&data).unwrap();
+    assert_eq!(capacity.blocks, 0x1_0000_0100);
+    assert_eq!(capacity.block_len, 512);
+}
@@ -0,0 +1,73 @@
+use crate::mmc::{
+    cd_text::{crc16, parse_cd_text},
+    subchannel::{parse_isrc, parse_mcn},
+};
+
+/// Builds a CD-TEXT pack with a valid CRC.
+fn pack(pack_type: u8, track: u8, sequence: u8, text: &[u8; 12]) -> Vec<u8> {
+    let mut pack = vec![pack_type, track, sequence, 0];
+    pack.extend_from_slice(text);
+    pack.extend_from_slice(&crc16(&pack).to_be_bytes());
+    pack
+}
+
+fn cd_text_data(packs: &[Vec<u8>]) -> Vec<u8> {
+    let len = (packs.len() * 18 + 2) as u16;
+    let mut data = len.to_be_bytes().to_vec();
+    data.extend_from_slice(&[0, 0]);
+    for pack in packs {
+        data.extend_from_slice(pack);
+    }
+    data
+}
+
+#[test]
+fn cd_text_titles() {
+    let mut size_info = [[0u8; 12]; 3];
+    size_info[2][4] = 0x09;
+    let mut packs = vec![
+        pack(0x80, 0, 0, b"Album\0First "),
+        pack(0x80, 1, 1, b"track\0\t\0\0\0\0\0"),
+        pack(0x81, 0, 2, b"Artist\0\0\0\0\0\0"),
+    ];
+    packs.extend((0..3).map(|i| pack(0x8f, i, 3 + i, &size_info[i as usize])));
+    let mut corrupted = pack(0x85, 0, 6, b"Message\0\0\0\0\0");
+    corrupted[17] ^= 1;
+    packs.push(corrupted);
+    let cd_text = parse_cd_text(&cd_text_data(&packs));
+    assert_eq!(cd_text.invalid_packs, 1);
+    assert_eq!(cd_text.blocks.len(), 1);
+    let block = &cd_text.blocks[0];
+    assert_eq!(block.language, 0x09);
+    assert_eq!(block.album.title.as_deref(), Some("Album"));
+    assert_eq!(block.album.performer.as_deref(), Some("Artist"));
+    assert_eq!(block.album.message, None);
+    assert_eq!(
+        block.track(1).unwrap().title.as_deref(),
+        Some("First track")
+    );
+    // A tab repeats the previous title
+    assert_eq!(
+        block.track(2).unwrap().title.as_deref(),
+        Some("First track")
+    );
+    assert_eq!(block.track(3), None);
+}
+
+#[test]
+fn empty_cd_text() {
+    assert!(parse_cd_text(&[0, 2, 0, 0]).is_empty());
+}
+
+#[test]
+fn mcn_and_isrc() {
+    let mut data = vec![0, 0, 0, 20, 2, 0, 0, 0, 0x80];
+    data.extend_from_slice(b"0724383406226\0\0");
+    assert_eq!(parse_mcn(&data).unwrap().as_deref(), Some("0724383406226"));
+    data[8] = 0;
+    assert_eq!(parse_mcn(&data).unwrap(), None);
+    let mut data = vec![0, 0, 0, 20, 3, 0x10, 1, 0, 0x80];
+    data.extend_from_slice(b"USRC17607839\0\0\0");
+    assert_eq!(parse_isrc(&data).unwrap().as_deref(), Some("USRC17607839"));
+    assert!(parse_isrc(&data[..12]).is_err());
+}
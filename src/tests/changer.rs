@@ -0,0 +1,28 @@
+use crate::mmc::mechanism::parse_mechanism_status;
+
+#[test]
+fn changer_slots() {
+    let data = [
+        0x02, 0x00, 0, 0, 0, 3, 0, 12, // Header, slot 2 of 3
+        0x80, 0, 0, 0, // Slot 0 has a disc
+        0x01, 0, 0, 0, // Slot 1 is empty
+        0x80, 0, 0, 0, // Slot 2 has a disc
+    ];
+    let status = parse_mechanism_status(&data).unwrap();
+    assert!(!status.door_open);
+    assert_eq!(status.current_slot, 2);
+    assert_eq!(status.slot_count, 3);
+    assert_eq!(status.slots, [true, false, true]);
+}
+
+#[test]
+fn door_and_current_slot() {
+    let status = parse_mechanism_status(&[0x00, 0x10, 0, 0, 0, 0, 0, 0]).unwrap();
+    assert!(status.door_open);
+    assert_eq!(status.slot_count, 0);
+    assert!(status.slots.is_empty());
+    // The high bits of the current slot are in the second byte
+    let status = parse_mechanism_status(&[0x01, 0x01, 0, 0, 0, 40, 0, 0]).unwrap();
+    assert_eq!(status.current_slot, 33);
+    assert!(parse_mechanism_status(&[0; 4]).is_err());
+}
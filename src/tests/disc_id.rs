@@ -0,0 +1,46 @@
+use crate::device::{Msf, Toc, TocTrack, TrackControl};
+
+fn toc(starts: &[(u32, u8)], lead_out_lba: u32) -> Toc {
+    let tracks: Vec<_> = (1..)
+        .zip(starts)
+        .map(|(number, &(start_lba, control))| TocTrack {
+            number,
+            session: 1,
+            start_lba,
+            start_msf: Msf::from_lba(start_lba),
+            control: TrackControl(control),
+        })
+        .collect();
+    Toc {
+        first_track: 1,
+        last_track: tracks.len() as u8,
+        tracks,
+        lead_out_lba,
+        lead_out_msf: Msf::from_lba(lead_out_lba),
+    }
+}
+
+#[test]
+fn musicbrainz_id() {
+    // Example from the MusicBrainz documentation
+    let starts = [0, 15213, 32164, 46442, 63264, 80339].map(|lba| (lba, 0));
+    let toc = toc(&starts, 95312);
+    assert_eq!(toc.musicbrainz_id(), "49HHV7Eb8UKF3aQiNmu1GR8vKTY-");
+    assert_eq!(
+        toc.musicbrainz_toc(),
+        "1 6 95462 150 15363 32314 46592 63414 80489"
+    );
+}
+
+#[test]
+fn enhanced_cd_musicbrainz_toc() {
+    let toc = toc(&[(0, 0), (20000, 0), (40000, 4)], 50000);
+    assert_eq!(toc.musicbrainz_toc(), "1 2 28750 150 20150");
+}
+
+#[test]
+fn freedb_id() {
+    let toc = toc(&[(0, 0), (1000, 0)], 2000);
+    assert_eq!(toc.freedb_id(), 0x08001a02);
+    assert_eq!(toc.cddb_query(), "cddb query 08001a02 2 150 1150 28");
+}
@@ -0,0 +1,37 @@
+use crate::{
+    device::{CdDiscType, DiscStatus, SessionState},
+    mmc::disc_info::parse_disc_info,
+};
+
+#[test]
+fn appendable_cd_rw() {
+    let mut data = [0u8; 34];
+    data[2] = 0x10 | (1 << 2) | 1; // erasable, incomplete session, incomplete disc
+    data[3] = 1;
+    data[4] = 2;
+    data[5] = 3;
+    data[6] = 3;
+    data[8] = 0x20;
+    data[20..24].copy_from_slice(&[0, 79, 59, 74]);
+    let info = parse_disc_info(&data, true).unwrap();
+    assert_eq!(info.status, DiscStatus::Incomplete);
+    assert_eq!(info.last_session_state, SessionState::Incomplete);
+    assert!(info.erasable && info.is_appendable() && !info.is_blank());
+    assert_eq!(info.sessions, 2);
+    assert_eq!(info.first_track_in_last_session, 3);
+    assert_eq!(info.disc_type, CdDiscType::CdRomXa);
+    assert_eq!(info.last_possible_lead_out_lba, Some(359_849));
+}
+
+#[test]
+fn finalized_dvd() {
+    let mut data = [0u8; 34];
+    data[2] = (3 << 2) | 2;
+    data[4] = 1;
+    data[8] = 0xff;
+    data[20..24].copy_from_slice(&[0xff; 4]);
+    let info = parse_disc_info(&data, false).unwrap();
+    assert_eq!(info.status, DiscStatus::Complete);
+    assert!(!info.is_appendable());
+    assert_eq!(info.last_possible_lead_out_lba, None);
+}
@@ -1,4 +1,7 @@
-use crate::error::ErrorKind as CrateErrorKind;
+use crate::{
+    error::{Error, ErrorKind as CrateErrorKind},
+    scsi::Sense,
+};
 use std::io::ErrorKind as StdErrorKind;
 
 #[test]
@@ -8,3 +11,21 @@ fn unknown_error_to_std_io_uncategorized() {
         "Uncategorized"
     );
 }
+
+#[test]
+fn busy_error_to_std_io_resource_busy() {
+    assert_eq!(
+        StdErrorKind::from(CrateErrorKind::Busy),
+        StdErrorKind::ResourceBusy
+    );
+}
+
+#[test]
+fn error_round_trip_through_std_io() {
+    let sense = Sense::parse(&[0x70, 0, 0x02, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0x3a, 0x01]).unwrap();
+    let error = Error::from(sense);
+    assert_eq!(error.kind(), CrateErrorKind::NoMedium);
+    let round_trip = Error::from(std::io::Error::from(error));
+    assert_eq!(round_trip.kind(), CrateErrorKind::NoMedium);
+    assert_eq!(round_trip.sense().unwrap().ascq(), 0x01);
+}
@@ -0,0 +1,92 @@
+use crate::{
+    device::{DeviceEvent, DriveStatus, EventTracker, Observation, PowerState},
+    mmc::events::{parse_busy, parse_media_event, parse_power_state},
+};
+use std::collections::VecDeque;
+
+fn observe(status: DriveStatus) -> Observation {
+    Observation {
+        status,
+        present: None,
+        eject_requested: false,
+        changed: false,
+    }
+}
+
+fn events(tracker: &mut EventTracker, observation: Observation) -> Vec<DeviceEvent> {
+    let mut events = VecDeque::new();
+    tracker.update(&observation, &mut events);
+    events.into()
+}
+
+#[test]
+fn media_event() {
+    let event = parse_media_event(&[0, 6, 0x04, 0x5e, 0x02, 0x02, 0, 0]).unwrap();
+    assert!(event.changed && event.present);
+    assert!(!event.tray_open && !event.eject_requested);
+    let event = parse_media_event(&[0, 6, 0x04, 0x5e, 0x01, 0x03, 0, 0]).unwrap();
+    assert!(event.eject_requested && event.tray_open);
+    assert!(!event.changed);
+    // No event available
+    assert!(parse_media_event(&[0, 2, 0x80, 0x5e]).is_err());
+}
+
+#[test]
+fn power_and_busy_events() {
+    let power = [0, 6, 0x02, 0x56, 0x02, 0x03, 0, 0];
+    assert_eq!(parse_power_state(&power).unwrap(), PowerState::Standby);
+    // Device busy class with the time until it's ready
+    let busy = [0, 6, 0x06, 0x56, 0x01, 0x01, 0, 20];
+    assert!(parse_busy(&busy, 6).unwrap());
+    // Operational change class with the drive available
+    let operational = [0, 6, 0x01, 0x56, 0x00, 0x00, 0, 0];
+    assert!(!parse_busy(&operational, 1).unwrap());
+    // The drive returned a different class than requested
+    assert!(parse_busy(&power, 6).is_err());
+}
+
+#[test]
+fn disc_inserted_and_removed() {
+    use DeviceEvent::*;
+    use DriveStatus::*;
+    let mut tracker = EventTracker::default();
+    assert_eq!(events(&mut tracker, observe(Empty)), []);
+    assert_eq!(events(&mut tracker, observe(Empty)), []);
+    assert_eq!(events(&mut tracker, observe(TrayOpen)), [TrayOpened]);
+    // The disc isn't known to be there until the drive is ready
+    assert_eq!(events(&mut tracker, observe(NotReady)), [TrayClosed]);
+    assert_eq!(
+        events(&mut tracker, observe(Loaded)),
+        [MediumInserted, Ready]
+    );
+    assert_eq!(events(&mut tracker, observe(Loaded)), []);
+    assert_eq!(
+        events(&mut tracker, observe(TrayOpen)),
+        [MediumRemoved, TrayOpened]
+    );
+}
+
+#[test]
+fn disc_replaced_between_polls() {
+    use DeviceEvent::*;
+    let mut tracker = EventTracker::default();
+    events(&mut tracker, observe(DriveStatus::Loaded));
+    let observation = Observation {
+        present: Some(true),
+        eject_requested: true,
+        changed: true,
+        ..observe(DriveStatus::Loaded)
+    };
+    assert_eq!(
+        events(&mut tracker, observation),
+        [EjectRequested, MediumRemoved, MediumInserted, Ready]
+    );
+    // The drive reports the medium before it's ready
+    let observation = Observation {
+        present: Some(true),
+        ..observe(DriveStatus::NotReady)
+    };
+    let mut tracker = EventTracker::default();
+    events(&mut tracker, observe(DriveStatus::Empty));
+    assert_eq!(events(&mut tracker, observation), [MediumInserted]);
+}
@@ -0,0 +1,34 @@
+use crate::{
+    device::{DeviceType, IdentifierAssociation, IdentifierKind},
+    mmc::inquiry::{parse_identifiers, parse_standard},
+};
+
+#[test]
+fn standard_inquiry() {
+    let mut data = vec![0x05, 0x80, 0x05, 0x32, 31, 0, 0, 0];
+    data.extend_from_slice(b"HL-DT-STDVDRAM GP57EB40 PF00");
+    let info = parse_standard(&data).unwrap();
+    assert_eq!(info.device_type, DeviceType::CdDvd);
+    assert!(info.removable);
+    assert_eq!(info.vendor, "HL-DT-ST");
+    assert_eq!(info.product, "DVDRAM GP57EB40");
+    assert_eq!(info.revision, "PF00");
+}
+
+#[test]
+fn device_identification_page() {
+    let page = [
+        0x02, 0x01, 0x00, 0x04, b'A', b'B', b'C', b' ', // ASCII T10 vendor ID
+        0x01, 0x03, 0x00, 0x02, 0x50, 0x0a, // binary NAA
+    ];
+    let identifiers = parse_identifiers(&page);
+    assert_eq!(identifiers.len(), 2);
+    assert_eq!(identifiers[0].kind, IdentifierKind::T10VendorId);
+    assert_eq!(
+        identifiers[0].association,
+        IdentifierAssociation::LogicalUnit
+    );
+    assert_eq!(identifiers[0].to_string(), "ABC");
+    assert_eq!(identifiers[1].kind, IdentifierKind::Naa);
+    assert_eq!(identifiers[1].to_string(), "500a");
+}
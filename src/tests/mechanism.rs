@@ -0,0 +1,49 @@
+use crate::{
+    device::LoadingMechanism,
+    mmc::{capabilities::parse_capabilities, mode::parse_mode_page},
+};
+
+#[test]
+fn capabilities_page() {
+    let mode_sense = [
+        0, 34, 0, 0, 0, 0, 0, 0, // Header without block descriptors
+        0x2a, 0x1a, // Page code and length
+        0x3b, 0x37, // Reads CD-R/RW and DVD-ROM/R/RAM, writes CD-R/RW and DVD-R/RAM
+        0x71, 0x73, // Audio play, multisession, CD-DA, accurate stream, C2, ISRC, UPC
+        0x29, 0x03, // Tray that can eject and lock, separate volume and mute
+        0x1b, 0x90, 0x01, 0x00, 0x08, 0x00, 0x1b, 0x90, // Max speed, volume levels, buffer
+        0, 0, 0x1b, 0x90, 0x1b, 0x90, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    let page = parse_mode_page(&mode_sense, 0x2a).unwrap();
+    let mechanism = parse_capabilities(&page).unwrap();
+    assert_eq!(mechanism.loading, LoadingMechanism::Tray);
+    assert!(mechanism.can_eject && mechanism.can_retract && mechanism.can_lock);
+    assert!(!mechanism.locked && !mechanism.prevent_jumper);
+    assert!(mechanism.read.cd_rw && mechanism.read.dvd_rom && mechanism.read.dvd_ram);
+    assert!(mechanism.write.cd_r && mechanism.write.dvd_r && !mechanism.write.dvd_rom);
+    assert!(mechanism.audio.play && mechanism.audio.read_cdda && mechanism.audio.c2_pointers);
+    assert!(mechanism.audio.isrc && mechanism.audio.mcn && mechanism.audio.separate_volume);
+    assert_eq!(mechanism.audio.volume_levels, Some(256));
+    assert_eq!(mechanism.max_read_speed, Some(7056));
+    assert_eq!(mechanism.max_write_speed, Some(7056));
+}
+
+#[test]
+fn slot_loading_drive() {
+    let mut page = [0u8; 20];
+    page[0] = 0x2a;
+    page[1] = 18;
+    // Caddy/slot type, can eject, locked
+    page[6] = 0x0b;
+    let mechanism = parse_capabilities(&page).unwrap();
+    assert_eq!(mechanism.loading, LoadingMechanism::Caddy);
+    assert!(mechanism.can_eject && !mechanism.can_retract && mechanism.locked);
+    assert_eq!(mechanism.max_read_speed, None);
+    assert_eq!(mechanism.audio.volume_levels, None);
+    assert!(parse_capabilities(&page[..12]).is_err());
+    // Changer with a tray
+    page[6] = 0x89;
+    let mechanism = parse_capabilities(&page).unwrap();
+    assert_eq!(mechanism.loading, LoadingMechanism::Changer);
+    assert!(mechanism.can_retract);
+}
@@ -1,2 +1,21 @@
+mod accuraterip;
+mod audio;
+mod capacity;
+mod cd_text;
+mod changer;
+mod disc_id;
+mod disc_info;
 mod error;
+mod events;
+mod info;
+mod mechanism;
+mod playback;
+mod profile;
+mod read_cd;
 mod real_dev_tests;
+mod sectors;
+mod secure_rip;
+mod sense;
+mod speed;
+mod toc;
+mod wait;
@@ -0,0 +1,42 @@
+use crate::{
+    device::{AudioState, Msf, Volume},
+    mmc::{
+        audio::{apply_volume, parse_volume},
+        mode::parse_mode_page,
+        subchannel::parse_position,
+    },
+};
+
+#[test]
+fn current_position() {
+    let data = [0, 0x11, 0, 12, 1, 0x10, 3, 1, 0, 12, 34, 56, 0, 1, 2, 3];
+    let status = parse_position(&data).unwrap();
+    assert_eq!(status.state, AudioState::Playing);
+    assert_eq!((status.track, status.index), (3, 1));
+    assert_eq!(status.absolute.to_string(), "12:34:56");
+    assert_eq!(
+        status.relative,
+        Msf {
+            minute: 1,
+            second: 2,
+            frame: 3
+        }
+    );
+    let status = parse_position(&[0, 0x15, 0, 0]).unwrap();
+    assert_eq!(status.state, AudioState::NoStatus);
+}
+
+#[test]
+fn audio_control_volume() {
+    let mode_sense = [
+        0, 22, 0, 0, 0, 0, 0, 0, // Header without block descriptors
+        0x8e, 0x0e, 0x04, 0, 0, 0, 0, 0, // Page code and flags
+        0x01, 0xff, 0x02, 0x80, 0, 0, 0, 0, // Ports
+    ];
+    let mut page = parse_mode_page(&mode_sense, 0x0e).unwrap();
+    assert_eq!(page.len(), 16);
+    assert_eq!(parse_volume(&page).unwrap(), Volume::stereo(0xff, 0x80));
+    apply_volume(&mut page, Volume::all(10)).unwrap();
+    assert_eq!(&page[8..], &[0x01, 10, 0x02, 10, 0, 10, 0, 10]);
+    assert!(parse_mode_page(&mode_sense, 0x2a).is_err());
+}
@@ -0,0 +1,33 @@
+use crate::{device::Profile, mmc::configuration::parse_features};
+
+#[test]
+fn get_configuration_features() {
+    let data = [
+        0, 0, 0, 32, 0, 0, 0x00, 0x40, // header, current profile: BD-ROM
+        0x00, 0x00, 0x03, 8, // profile list
+        0x00, 0x40, 0x01, 0, 0x00, 0x08, 0x00, 0, // BD-ROM (current), CD-ROM
+        0x00, 0x1e, 0x09, 4, 0, 0, 0, 0, // CD Read
+        0x00, 0x40, 0x03, 0, // BD Read
+    ];
+    let features = parse_features(&data).unwrap();
+    assert_eq!(features.current_profile, Some(Profile::BdRom));
+    assert_eq!(features.profiles, [Profile::BdRom, Profile::CdRom]);
+    assert_eq!(features.features.len(), 3);
+    let cd_read = features.get(0x001e).unwrap();
+    assert_eq!(cd_read.name(), Some("CD Read"));
+    assert_eq!(cd_read.version, 2);
+    assert!(cd_read.current);
+    assert!(!cd_read.persistent);
+    assert!(features.get(0x0040).unwrap().persistent);
+}
+
+#[test]
+fn profile_categories() {
+    assert!(Profile::from(0x000a).is_cd());
+    assert!(Profile::DvdPlusRDualLayer.is_dvd());
+    assert!(!Profile::HdDvdRom.is_dvd());
+    // Reserved codes in the DVD range
+    assert!(!Profile::from(0x0020).is_dvd());
+    assert_eq!(Profile::from(0x0043), Profile::BdRe);
+    assert_eq!(Profile::from(0x1234).code(), 0x1234);
+}
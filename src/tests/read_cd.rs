@@ -0,0 +1,69 @@
+use crate::{
+    device::{C2ErrorInfo, HeaderCodes, ReadCdOptions, SectorType, Subchannel},
+    error::ErrorKind,
+    mmc::read_cd::{command, split_sectors},
+};
+
+#[test]
+fn read_cd_command() {
+    let options = ReadCdOptions {
+        sector_type: SectorType::Mode2Form2,
+        headers: HeaderCodes::SubHeader,
+        c2_errors: C2ErrorInfo::PointersAndBlockBits,
+        subchannel: Subchannel::Q,
+        ..Default::default()
+    };
+    assert_eq!(
+        command(0x012345, 0x10, &options).unwrap(),
+        [0xbe, 0x14, 0, 0x01, 0x23, 0x45, 0, 0, 0x10, 0x54, 0x02, 0]
+    );
+    assert_eq!(command(0, 1, &ReadCdOptions::raw()).unwrap()[9], 0xf8);
+    assert!(command(0, 1 << 24, &options).is_err());
+}
+
+#[test]
+fn split_read_cd_sectors() {
+    let options = ReadCdOptions {
+        c2_errors: C2ErrorInfo::Pointers,
+        subchannel: Subchannel::Q,
+        ..ReadCdOptions::audio()
+    };
+    let sector_len = 2352 + 294 + 16;
+    let mut data = vec![0; sector_len * 2];
+    data[sector_len + 2352] = 0x80;
+    let sectors = split_sectors(&data, 100, 2, &options).unwrap();
+    assert_eq!(sectors.len(), 2);
+    assert_eq!(sectors[1].lba, 101);
+    assert_eq!(sectors[1].data.len(), 2352);
+    assert_eq!(sectors[1].c2_errors.len(), 294);
+    assert_eq!(sectors[1].subchannel.len(), 16);
+    assert!(!sectors[0].has_c2_errors());
+    assert!(sectors[1].has_c2_errors());
+    assert!(split_sectors(&data[1..], 100, 2, &options).is_err());
+}
+
+#[test]
+fn split_sectors_with_missing_fields() {
+    let options = ReadCdOptions {
+        c2_errors: C2ErrorInfo::Pointers,
+        ..ReadCdOptions::audio()
+    };
+    // The drive ignored the request for C2 pointers
+    let data = vec![0; 2352 * 2];
+    let error = split_sectors(&data, 0, 2, &options).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::UnsupportedOperation);
+    assert_eq!(
+        split_sectors(&data[..2352], 0, 2, &options)
+            .unwrap_err()
+            .kind(),
+        ErrorKind::Io
+    );
+    let data = vec![0; 2048 * 3];
+    let sectors = split_sectors(&data, 0, 3, &ReadCdOptions::default()).unwrap();
+    assert_eq!(sectors[2].data.len(), 2048);
+    let form2 = ReadCdOptions {
+        sector_type: SectorType::Mode2Form2,
+        ..Default::default()
+    };
+    assert!(split_sectors(&data, 0, 3, &form2).is_err());
+}
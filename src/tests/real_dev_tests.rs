@@ -36,15 +36,7 @@ fn b_retract() {
 #[test]
 #[ignore]
 fn b_status() {
-    let dev = get_device();
-    let mut status = dev.status().unwrap();
-    for _ in 0..20 {
-        if status == DriveStatus::Empty || status == DriveStatus::Loaded {
-            break;
-        }
-        sleep(Duration::from_secs(1));
-        status = dev.status().unwrap();
-    }
+    let status = get_device().wait_ready(Duration::from_secs(20)).unwrap();
     match status {
         DriveStatus::Empty | DriveStatus::Loaded => print!("{status:?} ... "),
         DriveStatus::NotReady | DriveStatus::TrayOpen => panic!("status: {status:?} :("),
@@ -80,6 +72,7 @@ fn e_toggle_eject() {
         let tray_opened = dev.toggle_eject().unwrap();
         assert_ne!(tray_opened, tray_was_open);
         assert_eq!(tray_opened, dev.status().unwrap().tray_open());
+        assert_eq!(tray_opened, dev.tray_open().unwrap());
     }
 }
 
@@ -0,0 +1,41 @@
+use crate::device::{Capacity, Device, ReadError};
+use std::{
+    env, fs,
+    io::{Read, Seek, SeekFrom},
+    process,
+};
+
+// A regular file can be used as a device that only supports reading
+#[test]
+#[cfg(target_os = "linux")]
+fn read_and_seek_unaligned() {
+    // Unique for each process so concurrent test runs don't share the file
+    let path = env::temp_dir().join(format!("eject_sectors_test_{}", process::id()));
+    let contents: Vec<u8> = (0..4096u32).map(|i| (i % 251) as u8).collect();
+    fs::write(&path, &contents).unwrap();
+    let device = Device::open(&path).unwrap();
+    // The last block is not in the file
+    let mut sectors = device.sectors_with_capacity(Capacity::new(9, 512)).unwrap();
+    assert_eq!(sectors.len(), 4608);
+
+    let mut buf = [0u8; 700];
+    sectors.seek(SeekFrom::Start(300)).unwrap();
+    sectors.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf[..], &contents[300..1000]);
+    assert_eq!(sectors.lba(), 1);
+
+    sectors.seek(SeekFrom::Current(-1000)).unwrap();
+    sectors.read_exact(&mut buf[..10]).unwrap();
+    assert_eq!(&buf[..10], &contents[..10]);
+    assert!(sectors.seek(SeekFrom::Current(-100)).is_err());
+
+    sectors.seek(SeekFrom::Start(4000)).unwrap();
+    let error = sectors.read_to_end(&mut Vec::new()).unwrap_err();
+    let error = error
+        .get_ref()
+        .unwrap()
+        .downcast_ref::<ReadError>()
+        .unwrap();
+    assert_eq!(error.lba(), 8);
+    let _ = fs::remove_file(path);
+}
@@ -0,0 +1,36 @@
+use crate::audio::secure::SectorReads;
+
+#[test]
+fn matching_reads_are_verified() {
+    let mut reads = SectorReads::default();
+    reads.add(&[1; 2352], &[]);
+    assert!(!reads.is_verified(2));
+    reads.add(&[2; 2352], &[]);
+    reads.add(&[1; 2352], &[]);
+    assert!(reads.is_verified(2));
+    assert_eq!(reads.data(), &[1; 2352]);
+    assert_eq!(reads.reads(), 3);
+}
+
+#[test]
+fn c2_errors_are_suspicious() {
+    let mut reads = SectorReads::default();
+    let mut c2_errors = [0; 294];
+    // Bytes 8 and 9, part of the third sample
+    c2_errors[1] = 0xc0;
+    reads.add(&[1; 2352], &c2_errors);
+    reads.add(&[1; 2352], &c2_errors);
+    assert!(!reads.is_verified(2));
+    let suspicious = reads.suspicious_bytes();
+    assert_eq!(suspicious.iter().filter(|&&byte| byte).count(), 2);
+    assert!(suspicious[8] && suspicious[9]);
+}
+
+#[test]
+fn unreadable_sector_is_silent() {
+    let mut reads = SectorReads::default();
+    reads.add_failure();
+    assert_eq!(reads.reads(), 1);
+    assert_eq!(reads.data(), &[0; 2352]);
+    assert!(reads.suspicious_bytes().iter().all(|&byte| byte));
+}
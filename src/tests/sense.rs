@@ -0,0 +1,54 @@
+use crate::{
+    device::DriveStatus,
+    error::{Error, ErrorKind},
+    mmc::unit_ready::status_from_sense,
+    scsi::{Sense, SenseKey},
+};
+
+#[test]
+fn fixed_format() {
+    let raw = [
+        0xf0, 0, 0x03, 0, 0, 0x12, 0x34, 10, 0, 0, 0, 0, 0x11, 0x05, 0, 0, 0, 0,
+    ];
+    let sense = Sense::parse(&raw).unwrap();
+    assert!(!sense.is_descriptor_format());
+    assert_eq!(sense.key(), SenseKey::MediumError);
+    assert_eq!((sense.asc(), sense.ascq()), (0x11, 0x05));
+    assert_eq!(sense.information(), Some(0x1234));
+    assert_eq!(sense.description(), "L-EC uncorrectable error");
+}
+
+#[test]
+fn descriptor_format() {
+    let raw = [
+        0x72, 0x05, 0x24, 0x00, 0, 0, 0, 12, // header
+        0x00, 0x0a, 0x80, 0, 0, 0, 0, 0, 0, 0, 0xab, 0xcd, // information descriptor
+    ];
+    let sense = Sense::parse(&raw).unwrap();
+    assert!(sense.is_descriptor_format());
+    assert_eq!(sense.key(), SenseKey::IllegalRequest);
+    assert_eq!(sense.information(), Some(0xabcd));
+    let error = Error::from(sense);
+    assert_eq!(error.kind(), ErrorKind::UnsupportedOperation);
+    assert_eq!(error.sense().unwrap().asc(), 0x24);
+}
+
+#[test]
+fn unknown_format() {
+    assert_eq!(Sense::parse(&[]), None);
+    assert_eq!(Sense::parse(&[0x7f, 0, 0x02]), None);
+}
+
+#[test]
+fn status_from_test_unit_ready() {
+    let status = |key: u8, asc: u8, ascq: u8| {
+        let raw = [0x70, 0, key, 0, 0, 0, 0, 10, 0, 0, 0, 0, asc, ascq];
+        status_from_sense(&Sense::parse(&raw).unwrap())
+    };
+    assert_eq!(status(0x02, 0x3a, 0x01), Some(DriveStatus::Empty));
+    assert_eq!(status(0x02, 0x3a, 0x02), Some(DriveStatus::TrayOpen));
+    assert_eq!(status(0x02, 0x3a, 0x00), Some(DriveStatus::Empty));
+    // Logical unit is in process of becoming ready
+    assert_eq!(status(0x02, 0x04, 0x01), Some(DriveStatus::NotReady));
+    assert_eq!(status(0x04, 0x44, 0x00), None);
+}
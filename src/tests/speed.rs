@@ -0,0 +1,39 @@
+use crate::mmc::speed::parse_speeds;
+
+#[test]
+fn write_speed_descriptors() {
+    let write_speeds = [
+        0, 0, 0, 36, 0, 0, 0, 0, // Header
+        0, 0, 0, 0, 0, 0x23, 0x05, 0x3f, 0, 0, 0x1b, 0x90, 0, 0, 0x1b, 0x90, // 40x
+        0, 0, 0, 0, 0, 0x23, 0x05, 0x3f, 0, 0, 0x1b, 0x90, 0, 0, 0x0d, 0xc8, // 20x
+    ];
+    let speeds = parse_speeds(&write_speeds, &[]).unwrap();
+    assert_eq!(speeds.read, [7056]);
+    assert_eq!(speeds.write, [7056, 3528]);
+}
+
+#[test]
+fn read_performance() {
+    let write_speeds = [0, 0, 0, 4, 0, 0, 0, 0];
+    let performance = [
+        0, 0, 0, 20, 0, 0, 0, 0, // Header
+        0, 0, 0, 0, 0, 0, 0x0d, 0xc8, 0, 0x05, 0x7d, 0x3f, 0, 0, 0x1e, 0xc0, // 7872 kB/s
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, // Past the data length
+    ];
+    let speeds = parse_speeds(&write_speeds, &performance).unwrap();
+    assert_eq!(speeds.read, [7872]);
+    assert!(speeds.write.is_empty());
+    assert!(parse_speeds(&[0, 0], &[]).is_err());
+}
+
+#[test]
+fn read_only_drive() {
+    let performance = [
+        0, 0, 0, 20, 0, 0, 0, 0, // Header
+        0, 0, 0, 0, 0, 0, 0x0d, 0xc8, 0, 0x05, 0x7d, 0x3f, 0, 0, 0x1e, 0xc0, // 7872 kB/s
+    ];
+    // The write speed query failed
+    let speeds = parse_speeds(&[], &performance).unwrap();
+    assert_eq!(speeds.read, [7872]);
+    assert!(speeds.write.is_empty());
+}
@@ -0,0 +1,69 @@
+use crate::{
+    device::Msf,
+    mmc::toc::{apply_sessions, parse_session_info, parse_toc},
+};
+
+#[test]
+fn toc_with_two_sessions() {
+    let data = [
+        0, 26, 1, 2, // header
+        0, 0x10, 1, 0, 0, 0, 0, 0, // track 1: audio at 0
+        0, 0x14, 2, 0, 0, 0, 0x2e, 0xe0, // track 2: data at 12000
+        0, 0x14, 0xaa, 0, 0, 0, 0x75, 0x30, // lead-out at 30000
+    ];
+    let mut toc = parse_toc(&data).unwrap();
+    assert_eq!((toc.first_track, toc.last_track), (1, 2));
+    assert_eq!(toc.tracks.len(), 2);
+    assert!(toc.tracks[0].control.is_audio());
+    assert!(toc.tracks[1].control.is_data());
+    assert_eq!(toc.track(2).unwrap().start_lba, 12000);
+    assert_eq!(toc.track_end(1), Some(12000));
+    assert_eq!(toc.track_end(2), Some(30000));
+    assert_eq!(toc.lead_out_msf.to_string(), "06:42:00");
+
+    let full_toc = [
+        0, 24, 1, 2, // header
+        1, 0x10, 0, 1, 0, 0, 0, 0, 0, 2, 0, // session 1, track 1
+        2, 0x14, 0, 2, 0, 0, 0, 0, 2, 42, 0, // session 2, track 2
+    ];
+    apply_sessions(&mut toc, &full_toc);
+    assert_eq!(toc.tracks[0].session, 1);
+    assert_eq!(toc.tracks[1].session, 2);
+    assert_eq!(toc.track_end(1), Some(600));
+}
+
+#[test]
+fn enhanced_cd_from_last_session() {
+    let data = [
+        0, 34, 1, 3, // header
+        0, 0x10, 1, 0, 0, 0, 0, 0, // track 1: audio at 0
+        0, 0x10, 2, 0, 0, 0, 0x2e, 0xe0, // track 2: audio at 12000
+        0, 0x14, 3, 0, 0, 0, 0x75, 0x30, // track 3: data at 30000
+        0, 0x14, 0xaa, 0, 0, 0, 0xc3, 0x50, // lead-out at 50000
+    ];
+    // The Linux ioctls only tell where the last session starts
+    let mut toc = parse_toc(&data).unwrap();
+    toc.split_last_session(30000);
+    let sessions: Vec<u8> = toc.tracks.iter().map(|track| track.session).collect();
+    assert_eq!(sessions, [1, 1, 2]);
+    assert_eq!(toc.track_end(1), Some(12000));
+    // The last audio track doesn't include the gap before the data session
+    assert_eq!(toc.track_end(2), Some(18600));
+    assert_eq!(toc.track_end(3), Some(50000));
+}
+
+#[test]
+fn msf_conversion() {
+    let msf = Msf::from_lba(12000);
+    assert_eq!((msf.minute, msf.second, msf.frame), (2, 42, 0));
+    assert_eq!(msf.to_lba(), 12000);
+    assert_eq!(Msf::from_lba(74).frame, 74);
+}
+
+#[test]
+fn session_info() {
+    let data = [0, 10, 1, 2, 0, 0x14, 3, 0, 0, 0, 0x5d, 0xc0];
+    let session = parse_session_info(&data).unwrap();
+    assert!(session.multisession);
+    assert_eq!(session.start_lba, 24000);
+}
@@ -0,0 +1,47 @@
+use crate::{
+    device::{retracted, wait_for, DriveStatus, StatusCondition},
+    error::ErrorKind,
+};
+use std::time::Duration;
+
+#[test]
+fn status_conditions() {
+    assert!(DriveStatus::Loaded.is_met(DriveStatus::Loaded));
+    assert!(!DriveStatus::Loaded.is_met(DriveStatus::NotReady));
+    let closed = |status: DriveStatus| !status.tray_open();
+    assert!(closed.is_met(DriveStatus::Empty));
+    assert!(!closed.is_met(DriveStatus::TrayOpen));
+}
+
+#[test]
+fn wait_until_met() {
+    let mut statuses = [
+        DriveStatus::TrayOpen,
+        DriveStatus::NotReady,
+        DriveStatus::Empty,
+    ]
+    .into_iter();
+    let status = wait_for(
+        || Ok(statuses.next().unwrap()),
+        retracted,
+        Duration::from_secs(5),
+    );
+    assert_eq!(status.unwrap(), DriveStatus::Empty);
+    assert!(retracted(DriveStatus::Loaded));
+    assert!(!retracted(DriveStatus::NotReady));
+}
+
+#[test]
+fn wait_timeout() {
+    let mut statuses = [DriveStatus::TrayOpen, DriveStatus::NotReady].into_iter();
+    let error = wait_for(
+        || Ok(statuses.next().unwrap_or(DriveStatus::NotReady)),
+        DriveStatus::Loaded,
+        Duration::from_millis(300),
+    )
+    .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::TimedOut);
+    assert_eq!(error.status(), Some(DriveStatus::NotReady));
+    let error = wait_for(|| Ok(DriveStatus::TrayOpen), retracted, Duration::ZERO).unwrap_err();
+    assert_eq!(error.status(), Some(DriveStatus::TrayOpen));
+}